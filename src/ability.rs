@@ -0,0 +1,145 @@
+use crate::status::StatusApplication;
+use serde::{Deserialize, Serialize};
+
+/// Compétence de classe déclarée dans attributes.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ability {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Niveau à partir duquel la compétence est utilisable
+    #[serde(default = "niveau_initial")]
+    pub level: u32,
+    /// Mana dépensé à chaque utilisation
+    #[serde(default)]
+    pub cost: i32,
+    pub effect: AbilityEffect,
+    /// Altérations lancées : les néfastes sur l'adversaire, les autres sur soi
+    #[serde(default)]
+    pub statuses: Vec<StatusApplication>,
+}
+
+/// Effet d'une compétence, ex: `{ "type": "degats", "multiplier": 1.8 }`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AbilityEffect {
+    /// Une attaque dont les dégâts sont multipliés
+    Degats { multiplier: f32 },
+    /// Plusieurs attaques d'affilée, chacune pouvant rater
+    Rafale { hits: u32, multiplier: f32 },
+    /// Rend des points de vie
+    Soin { amount: i32 },
+    /// Réduit de `reduction` % les dégâts des attaques du prochain tour adverse
+    Garde { reduction: u32 },
+}
+
+fn niveau_initial() -> u32 {
+    1
+}
+
+impl Ability {
+    pub fn is_unlocked(&self, level: u32) -> bool {
+        level >= self.level
+    }
+
+    /// Vise un adversaire : attaque, ou altération néfaste à lancer
+    pub fn is_offensive(&self) -> bool {
+        matches!(self.effect, AbilityEffect::Degats { .. } | AbilityEffect::Rafale { .. })
+            || self.statuses.iter().any(|application| application.effect.kind.is_harmful())
+    }
+
+    /// Incohérences de la compétence (coût, multiplicateurs...)
+    pub fn problems(&self) -> Vec<String> {
+        let mut problemes = Vec::new();
+        if self.cost < 0 {
+            problemes.push(format!("'{}' : coût {} négatif", self.name, self.cost));
+        }
+        match self.effect {
+            AbilityEffect::Degats { multiplier } | AbilityEffect::Rafale { multiplier, .. } if !(multiplier.is_finite() && multiplier > 0.0) => {
+                problemes.push(format!("'{}' : multiplicateur {} invalide", self.name, multiplier));
+            }
+            AbilityEffect::Soin { amount } if amount <= 0 => {
+                problemes.push(format!("'{}' : soin {} invalide", self.name, amount));
+            }
+            AbilityEffect::Garde { reduction } if reduction > 100 => {
+                problemes.push(format!("'{}' : réduction {} % supérieure à 100", self.name, reduction));
+            }
+            _ => {}
+        }
+        if let AbilityEffect::Rafale { hits: 0, .. } = self.effect {
+            problemes.push(format!("'{}' : une rafale doit porter au moins un coup", self.name));
+        }
+        for application in &self.statuses {
+            problemes.extend(application.problems().into_iter().map(|p| format!("'{}' : {}", self.name, p)));
+        }
+        problemes
+    }
+
+    /// Résumé de l'effet pour le menu de combat, ex: "dégâts x1.8"
+    pub fn describe_effect(&self) -> String {
+        let effet = match self.effect {
+            AbilityEffect::Degats { multiplier } => format!("dégâts x{}", multiplier),
+            AbilityEffect::Rafale { hits, multiplier } => format!("{} coups à x{}", hits, multiplier),
+            AbilityEffect::Soin { amount } => format!("+{} PV", amount),
+            AbilityEffect::Garde { reduction } => format!("-{} % de dégâts subis", reduction),
+        };
+        let statuts: Vec<String> = self.statuses.iter().map(|a| a.effect.kind.name().to_lowercase()).collect();
+        if statuts.is_empty() {
+            effet
+        } else {
+            format!("{} + {}", effet, statuts.join(", "))
+        }
+    }
+}
+
+/// Dégâts d'une attaque réussie modifiés par une compétence (au moins 1)
+pub fn scale_damage(damage: i32, multiplier: f32) -> i32 {
+    ((damage as f32 * multiplier).round() as i32).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_competences_depuis_json() {
+        let competences: Vec<Ability> = serde_json::from_str(r#"[
+            {"name": "Boule de Feu", "cost": 15, "effect": {"type": "degats", "multiplier": 2.5}},
+            {"name": "Tourbillon", "level": 4, "cost": 20, "effect": {"type": "rafale", "hits": 3, "multiplier": 0.7}},
+            {"name": "Soin Mineur", "level": 2, "effect": {"type": "soin", "amount": 30}},
+            {"name": "Posture", "effect": {"type": "garde", "reduction": 60}},
+            {"name": "Coup Sournois", "effect": {"type": "degats", "multiplier": 2.0},
+             "statuses": [{"kind": "poison", "duration": 3, "potency": 4, "chance": 50}]}
+        ]"#).unwrap();
+
+        assert_eq!(competences[0].level, 1);
+        assert_eq!(competences[1].effect, AbilityEffect::Rafale { hits: 3, multiplier: 0.7 });
+        assert!(!competences[1].is_unlocked(3));
+        assert!(competences[1].is_unlocked(4));
+        assert_eq!(competences[2].cost, 0);
+        assert_eq!(competences[3].describe_effect(), "-60 % de dégâts subis");
+        assert_eq!(competences[4].describe_effect(), "dégâts x2 + poison");
+        assert!(competences[4].is_offensive());
+        assert!(!competences[2].is_offensive());
+        assert!(competences.iter().all(|c| c.problems().is_empty()));
+    }
+
+    #[test]
+    fn test_incoherences_et_degats() {
+        let rafale = Ability {
+            name: "Vide".into(),
+            description: String::new(),
+            level: 1,
+            cost: -5,
+            effect: AbilityEffect::Rafale { hits: 0, multiplier: 0.0 },
+            statuses: Vec::new(),
+        };
+        assert_eq!(rafale.problems().len(), 3);
+
+        let demesure = Ability { cost: 5, effect: AbilityEffect::Degats { multiplier: f32::INFINITY }, ..rafale };
+        assert_eq!(demesure.problems(), vec!["'Vide' : multiplicateur inf invalide".to_string()]);
+
+        assert_eq!(scale_damage(4, 2.5), 10);
+        assert_eq!(scale_damage(1, 0.3), 1);
+    }
+}
@@ -0,0 +1,52 @@
+use rpg::data_loader::load_raw_data;
+use rpg::lint::{lint, Severity};
+use rpg::player::START_ZONE_ID;
+use std::process::ExitCode;
+
+/// Vérifie le contenu d'un dossier de données.
+/// Usage : rpg-lint [dossier] [--json] (dossier par défaut : $RPG_DATA_DIR ou data)
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let json = args.iter().any(|arg| arg == "--json");
+    let data_dir = args
+        .iter()
+        .find(|arg| !arg.starts_with("--"))
+        .cloned()
+        .or_else(|| std::env::var("RPG_DATA_DIR").ok())
+        .unwrap_or_else(|| "data".to_string());
+
+    let raw = match load_raw_data(&data_dir) {
+        Ok(raw) => raw,
+        Err(e) => {
+            eprintln!("❌ Impossible de charger '{}' : {}", data_dir, e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let report = lint(&raw, START_ZONE_ID);
+
+    if json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(output) => println!("{}", output),
+            Err(e) => {
+                eprintln!("❌ Sérialisation du rapport impossible : {}", e);
+                return ExitCode::from(2);
+            }
+        }
+    } else {
+        for issue in &report.issues {
+            let label = match issue.severity {
+                Severity::Error => "❌ erreur",
+                Severity::Warning => "⚠️  avertissement",
+            };
+            println!("{} [{}] {}", label, issue.code, issue.detail);
+        }
+        println!("{} erreur(s), {} avertissement(s)", report.errors, report.warnings);
+    }
+
+    if report.has_errors() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
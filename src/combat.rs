@@ -0,0 +1,206 @@
+use std::collections::VecDeque;
+use crate::rng::GameRng;
+
+/// Participant d'un combat, dans l'ordre d'initiative
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combatant {
+    Joueur,
+    Monstre(usize), // Emplacement du monstre dans la zone
+}
+
+/// Chance (en %) d'agir une seconde fois dans le tour :
+/// 25% pour un combattant au moins deux fois plus agile que son adversaire le plus rapide
+pub fn extra_action_chance(agility: i32, opponent_agility: i32) -> u32 {
+    if agility > 0 && agility >= opponent_agility * 2 {
+        25
+    } else {
+        0
+    }
+}
+
+/// Ordre d'action d'un tour à partir de l'agilité de chaque combattant.
+/// Le plus agile agit en premier, les égalités sont départagées au hasard ;
+/// les actions supplémentaires des combattants très rapides s'ajoutent en fin de tour.
+pub fn initiative_order(combatants: &[(Combatant, i32)], rng: &mut GameRng) -> Vec<Combatant> {
+    let mut tirages: Vec<(i32, u64, Combatant)> = combatants
+        .iter()
+        .map(|&(combatant, agility)| (agility, rng.next_u64(), combatant))
+        .collect();
+    tirages.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+
+    let mut ordre: Vec<Combatant> = tirages.iter().map(|&(_, _, combatant)| combatant).collect();
+    for &(agility, _, combatant) in &tirages {
+        let est_joueur = combatant == Combatant::Joueur;
+        let adversaire = combatants
+            .iter()
+            .filter(|(autre, _)| (*autre == Combatant::Joueur) != est_joueur)
+            .map(|&(_, agility)| agility)
+            .max();
+        let chance = adversaire.map_or(0, |adversaire| extra_action_chance(agility, adversaire));
+        if chance > 0 && rng.chance(chance) {
+            ordre.push(combatant);
+        }
+    }
+    ordre
+}
+
+/// Enchaînement des tours d'un combat : surprise, initiative et étourdissements.
+/// Aucune entrée/sortie ici : `Game` annonce et applique chaque action.
+#[derive(Debug, Clone)]
+pub struct CombatRounds {
+    surprise: bool, // Le prochain tour est un tour de surprise
+}
+
+impl CombatRounds {
+    /// En embuscade, le joueur est surpris si au moins un monstre est plus agile que lui
+    pub fn new(ambush: bool, player_agility: i32, monster_agilities: &[i32]) -> Self {
+        CombatRounds {
+            surprise: ambush && monster_agilities.iter().any(|&agility| agility > player_agility),
+        }
+    }
+
+    pub fn is_surprise(&self) -> bool {
+        self.surprise
+    }
+
+    /// Tour suivant, d'après l'agilité du joueur et des monstres encore debout (emplacement, agilité).
+    /// Surpris, le joueur n'agit pas : seuls les monstres plus agiles que lui profitent de ce premier tour.
+    pub fn next_round(&mut self, player_agility: i32, monsters: &[(usize, i32)], rng: &mut GameRng) -> Round {
+        let surprise = std::mem::take(&mut self.surprise);
+        let mut combattants = Vec::new();
+        if !surprise {
+            combattants.push((Combatant::Joueur, player_agility));
+        }
+        for &(slot, agility) in monsters {
+            if !surprise || agility > player_agility {
+                combattants.push((Combatant::Monstre(slot), agility));
+            }
+        }
+        Round {
+            surprise,
+            order: initiative_order(&combattants, rng).into(),
+            acted: Vec::new(),
+            stunned: Vec::new(),
+        }
+    }
+}
+
+/// Action d'un combattant dans le tour
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Action {
+    pub combatant: Combatant,
+    pub first: bool, // Première action du tour : ses altérations agissent avant
+}
+
+/// Un tour de combat, joué action par action
+#[derive(Debug, Clone)]
+pub struct Round {
+    pub surprise: bool,
+    order: VecDeque<Combatant>,
+    acted: Vec<Combatant>,
+    stunned: Vec<Combatant>,
+}
+
+impl Round {
+    /// Prochaine action du tour ; les actions supplémentaires d'un combattant étourdi sont sautées
+    pub fn next_action(&mut self) -> Option<Action> {
+        while let Some(combatant) = self.order.pop_front() {
+            let first = !self.acted.contains(&combatant);
+            if !first && self.stunned.contains(&combatant) {
+                continue;
+            }
+            self.acted.push(combatant);
+            return Some(Action { combatant, first });
+        }
+        None
+    }
+
+    /// Étourdi à sa première action, le combattant perd tout le tour
+    pub fn stun(&mut self, combatant: Combatant) {
+        self.stunned.push(combatant);
+    }
+
+    pub fn is_stunned(&self, combatant: Combatant) -> bool {
+        self.stunned.contains(&combatant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordre_d_initiative() {
+        let mut rng = GameRng::new(7);
+        let combattants = [(Combatant::Joueur, 10), (Combatant::Monstre(0), 12), (Combatant::Monstre(1), 3)];
+        let ordre = initiative_order(&combattants, &mut rng);
+        assert_eq!(ordre, vec![Combatant::Monstre(0), Combatant::Joueur, Combatant::Monstre(1)]);
+
+        // Égalité : chacun passe parfois en premier
+        let egalite = [(Combatant::Joueur, 8), (Combatant::Monstre(0), 8)];
+        let premiers = (0..200)
+            .filter(|_| initiative_order(&egalite, &mut rng)[0] == Combatant::Joueur)
+            .count();
+        assert!((60..140).contains(&premiers));
+    }
+
+    #[test]
+    fn test_action_supplementaire_des_plus_rapides() {
+        assert_eq!(extra_action_chance(20, 10), 25);
+        assert_eq!(extra_action_chance(19, 10), 0);
+        assert_eq!(extra_action_chance(0, 0), 0);
+
+        let mut rng = GameRng::new(3);
+        let combattants = [(Combatant::Joueur, 30), (Combatant::Monstre(0), 10), (Combatant::Monstre(1), 12)];
+        let mut doubles = 0;
+        for _ in 0..400 {
+            let ordre = initiative_order(&combattants, &mut rng);
+            assert!(ordre.len() <= 4);
+            if ordre.len() == 4 {
+                // L'action supplémentaire vient après tout le monde
+                assert_eq!(ordre[3], Combatant::Joueur);
+                doubles += 1;
+            }
+        }
+        assert!((60..140).contains(&doubles));
+    }
+
+    #[test]
+    fn test_tour_de_surprise() {
+        assert!(CombatRounds::new(true, 5, &[3, 8]).is_surprise());
+        assert!(!CombatRounds::new(true, 8, &[3, 8]).is_surprise());
+        assert!(!CombatRounds::new(false, 5, &[3, 8]).is_surprise());
+
+        // Seul le monstre plus agile que le joueur agit pendant la surprise, puis tout le monde
+        let mut rng = GameRng::new(1);
+        let mut rounds = CombatRounds::new(true, 5, &[3, 8]);
+        let mut surprise = rounds.next_round(5, &[(0, 3), (1, 8)], &mut rng);
+        assert!(surprise.surprise);
+        assert_eq!(surprise.next_action(), Some(Action { combatant: Combatant::Monstre(1), first: true }));
+        assert_eq!(surprise.next_action(), None);
+
+        let suivant = rounds.next_round(5, &[(0, 3), (1, 8)], &mut rng);
+        assert!(!suivant.surprise);
+        assert_eq!(suivant.order, [Combatant::Monstre(1), Combatant::Joueur, Combatant::Monstre(0)]);
+    }
+
+    #[test]
+    fn test_etourdi_perd_tout_le_tour() {
+        let tour = |order: Vec<Combatant>| Round { surprise: false, order: order.into(), acted: Vec::new(), stunned: Vec::new() };
+        let joueur = |first| Some(Action { combatant: Combatant::Joueur, first });
+
+        // Vif, le joueur agit une seconde fois en fin de tour
+        let mut vif = tour(vec![Combatant::Joueur, Combatant::Monstre(0), Combatant::Joueur]);
+        assert_eq!(vif.next_action(), joueur(true));
+        assert_eq!(vif.next_action(), Some(Action { combatant: Combatant::Monstre(0), first: true }));
+        assert_eq!(vif.next_action(), joueur(false));
+
+        // Étourdi, il perd aussi son action supplémentaire
+        let mut etourdi = tour(vec![Combatant::Joueur, Combatant::Monstre(0), Combatant::Joueur]);
+        assert_eq!(etourdi.next_action(), joueur(true));
+        etourdi.stun(Combatant::Joueur);
+        assert!(etourdi.is_stunned(Combatant::Joueur));
+        assert_eq!(etourdi.next_action(), Some(Action { combatant: Combatant::Monstre(0), first: true }));
+        assert_eq!(etourdi.next_action(), None);
+    }
+}
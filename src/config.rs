@@ -0,0 +1,160 @@
+use crate::data_loader::ValidationMode;
+use crate::error::GameError;
+use std::path::Path;
+
+/// Options reconnues sur la ligne de commande
+pub const USAGE: &str = "Usage : rpg [--data-dir DOSSIER] [--save-dir DOSSIER] [--save-file FICHIER] [--seed N] [--strict]
+Variables d'environnement : RPG_DATA_DIR, RPG_SAVE_DIR, RPG_SAVE_FILE, RPG_SEED";
+
+/// Emplacements des données et de la sauvegarde
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameConfig {
+    /// Dossier contenant items.json, zones.json, etc.
+    pub data_dir: String,
+    /// Dossier où écrire la sauvegarde
+    pub save_dir: String,
+    /// Nom du fichier de sauvegarde
+    pub save_file: String,
+    pub validation: ValidationMode,
+    /// Graine aléatoire imposée (sinon celle de la sauvegarde ou de l'horloge)
+    pub seed: Option<u64>,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            data_dir: "data".to_string(),
+            save_dir: "data".to_string(),
+            save_file: "perso_save.json".to_string(),
+            validation: ValidationMode::Lenient,
+            seed: None,
+        }
+    }
+}
+
+impl GameConfig {
+    /// Construit la configuration depuis l'environnement puis les arguments du programme
+    pub fn from_env_and_args() -> Result<Self, GameError> {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        Self::from_sources(&args, |name| std::env::var(name).ok())
+    }
+
+    /// Les arguments l'emportent sur les variables d'environnement,
+    /// qui l'emportent sur les valeurs par défaut.
+    pub fn from_sources(args: &[String], env: impl Fn(&str) -> Option<String>) -> Result<Self, GameError> {
+        let mut config = GameConfig::default();
+
+        if let Some(dir) = env("RPG_DATA_DIR") {
+            config.data_dir = dir;
+        }
+        if let Some(dir) = env("RPG_SAVE_DIR") {
+            config.save_dir = dir;
+        }
+        if let Some(file) = env("RPG_SAVE_FILE") {
+            config.save_file = file;
+        }
+        if let Some(seed) = env("RPG_SEED") {
+            config.seed = Some(parse_seed(&seed)?);
+        }
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let target = match arg.as_str() {
+                "--strict" => {
+                    config.validation = ValidationMode::Strict;
+                    continue;
+                }
+                "--seed" => {
+                    let value = args.next().ok_or_else(|| missing_value(arg))?;
+                    config.seed = Some(parse_seed(value)?);
+                    continue;
+                }
+                "--data-dir" => &mut config.data_dir,
+                "--save-dir" => &mut config.save_dir,
+                "--save-file" => &mut config.save_file,
+                _ => return Err(GameError::InvalidArgument(format!("option inconnue '{}'\n{}", arg, USAGE))),
+            };
+            *target = args.next().ok_or_else(|| missing_value(arg))?.clone();
+        }
+
+        Ok(config)
+    }
+
+    /// Chemin d'un fichier du dossier de données
+    pub fn data_path(&self, file: &str) -> String {
+        Path::new(&self.data_dir).join(file).to_string_lossy().into_owned()
+    }
+
+    /// Chemin complet du fichier de sauvegarde
+    pub fn save_path(&self) -> String {
+        Path::new(&self.save_dir).join(&self.save_file).to_string_lossy().into_owned()
+    }
+}
+
+fn missing_value(option: &str) -> GameError {
+    GameError::InvalidArgument(format!("valeur manquante après '{}'\n{}", option, USAGE))
+}
+
+fn parse_seed(value: &str) -> Result<u64, GameError> {
+    value
+        .parse()
+        .map_err(|_| GameError::InvalidArgument(format!("graine invalide '{}' (entier positif attendu)", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_valeurs_par_defaut() {
+        let config = GameConfig::from_sources(&[], |_| None).unwrap();
+        assert_eq!(config, GameConfig::default());
+        assert_eq!(config.data_path("items.json"), "data/items.json");
+        assert_eq!(config.save_path(), "data/perso_save.json");
+    }
+
+    #[test]
+    fn test_arguments_prioritaires_sur_environnement() {
+        let env = |name: &str| match name {
+            "RPG_DATA_DIR" => Some("packs/hiver".to_string()),
+            "RPG_SAVE_DIR" => Some("/tmp/saves".to_string()),
+            _ => None,
+        };
+        let config = GameConfig::from_sources(&args(&["--data-dir", "packs/ete", "--strict"]), env).unwrap();
+        assert_eq!(config.data_dir, "packs/ete");
+        assert_eq!(config.save_dir, "/tmp/saves");
+        assert_eq!(config.validation, ValidationMode::Strict);
+        assert_eq!(config.save_path(), "/tmp/saves/perso_save.json");
+    }
+
+    #[test]
+    fn test_fichier_de_sauvegarde() {
+        let config = GameConfig::from_sources(&args(&["--save-file", "alice.json"]), |_| None).unwrap();
+        assert_eq!(config.save_path(), "data/alice.json");
+    }
+
+    #[test]
+    fn test_graine() {
+        let env = |name: &str| (name == "RPG_SEED").then(|| "7".to_string());
+        assert_eq!(GameConfig::from_sources(&[], env).unwrap().seed, Some(7));
+
+        let config = GameConfig::from_sources(&args(&["--seed", "123"]), env).unwrap();
+        assert_eq!(config.seed, Some(123));
+
+        let invalide = GameConfig::from_sources(&args(&["--seed", "abc"]), |_| None);
+        assert!(matches!(invalide, Err(GameError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_arguments_invalides() {
+        let inconnu = GameConfig::from_sources(&args(&["--vitesse"]), |_| None);
+        assert!(matches!(inconnu, Err(GameError::InvalidArgument(_))));
+
+        let sans_valeur = GameConfig::from_sources(&args(&["--data-dir"]), |_| None);
+        assert!(matches!(sans_valeur, Err(GameError::InvalidArgument(_))));
+    }
+}
@@ -1,55 +1,754 @@
-use crate::{item::Item, quest::Quest, npc::{Npc, NpcRaw}, zone::{Zone, ZoneRaw}, monster::{Monster, MonsterRaw}};
-use serde_json;
+use crate::{item::Item, quest::{Objective, Quest}, npc::{Npc, NpcRaw}, zone::{Direction, Zone, ZoneRaw}, monster::{Monster, MonsterRaw}};
+use crate::dialogue::{Condition, Effect};
+use crate::error::GameError;
+use crate::progression::{ClassProfile, LevelCurve};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
-use std::error::Error;
+use std::fmt;
 use std::fs;
 
-pub fn load_items(path: &str) -> Result<Vec<Item>, Box<dyn Error>> {
-    let data = fs::read_to_string(path)?;
-    let items = serde_json::from_str(&data)?;
-    Ok(items)
+/// Lit et désérialise un fichier JSON
+pub(crate) fn read_json<T: DeserializeOwned>(path: &str) -> Result<T, GameError> {
+    let data = fs::read_to_string(path).map_err(|e| GameError::io(path, e))?;
+    serde_json::from_str(&data).map_err(|source| GameError::Parse { path: path.to_string(), source })
 }
 
-pub fn load_quests(path: &str) -> Result<Vec<Quest>, Box<dyn Error>> {
-    let data = fs::read_to_string(path)?;
-    let quests = serde_json::from_str(&data)?;
-    Ok(quests)
+pub fn load_items(path: &str) -> Result<Vec<Item>, GameError> {
+    read_json(path)
 }
 
-pub fn load_npcs(path: &str, all_quests: &[Quest]) -> Result<Vec<Npc>, Box<dyn Error>> {
-    let data = fs::read_to_string(path)?;
-    let npcs_raw: Vec<NpcRaw> = serde_json::from_str(&data)?;
+pub fn load_quests(path: &str) -> Result<Vec<Quest>, GameError> {
+    read_json(path)
+}
 
-    let npcs = npcs_raw
-        .into_iter()
-        .map(|raw| Npc::from_raw(raw, all_quests))
-        .collect();
+pub fn load_npcs(path: &str, all_quests: &[Quest]) -> Result<Vec<Npc>, GameError> {
+    let npcs_raw: Vec<NpcRaw> = read_json(path)?;
+    Ok(resolve_npcs(npcs_raw, all_quests))
+}
 
-    Ok(npcs)
+pub fn load_monsters(path: &str) -> Result<Vec<Monster>, GameError> {
+    let monsters_raw: Vec<MonsterRaw> = read_json(path)?;
+    Ok(resolve_monsters(monsters_raw))
 }
 
-pub fn load_monsters(path: &str) -> Result<Vec<Monster>, Box<dyn Error>> {
-    let data = fs::read_to_string(path)?;
-    let monsters_raw: Vec<MonsterRaw> = serde_json::from_str(&data)?;
+pub fn load_zones(path: &str, all_npcs: &[Npc], all_monsters: &[Monster]) -> Result<HashMap<u32, Zone>, GameError> {
+    let zones_raw: Vec<ZoneRaw> = read_json(path)?;
+    Ok(resolve_zones(zones_raw, all_npcs, all_monsters))
+}
 
-    let monsters = monsters_raw
+fn resolve_npcs(npcs_raw: Vec<NpcRaw>, all_quests: &[Quest]) -> Vec<Npc> {
+    npcs_raw
         .into_iter()
-        .map(|raw| Monster::from_raw(raw))
-        .collect();
-
-    Ok(monsters)
+        .map(|raw| Npc::from_raw(raw, all_quests))
+        .collect()
 }
 
-pub fn load_zones(path: &str, all_npcs: &[Npc], all_monsters: &[Monster]) -> Result<HashMap<u32, Zone>, Box<dyn Error>> {
-    let data = fs::read_to_string(path)?;
-    let zones_raw: Vec<ZoneRaw> = serde_json::from_str(&data)?;
+fn resolve_monsters(monsters_raw: Vec<MonsterRaw>) -> Vec<Monster> {
+    monsters_raw
+        .into_iter()
+        .map(Monster::from_raw)
+        .collect()
+}
 
+fn resolve_zones(zones_raw: Vec<ZoneRaw>, all_npcs: &[Npc], all_monsters: &[Monster]) -> HashMap<u32, Zone> {
     let mut zones = HashMap::new();
     for raw in zones_raw {
         let zone_id = raw.id;
         let zone = Zone::from_raw(raw, all_npcs, all_monsters);
         zones.insert(zone_id, zone);
     }
+    zones
+}
+
+/// Comportement du jeu face à des données incohérentes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Refuse de démarrer au moindre problème
+    Strict,
+    /// Signale les problèmes mais démarre quand même
+    Lenient,
+}
+
+/// Nature d'un problème de données ; `rpg-lint` en tire le code du problème
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum IssueKind {
+    /// L'ID (ou le nom) référencé n'existe pas
+    MissingReference,
+    /// Valeur incohérente : probabilité, fourchette, groupe vide, ID en double...
+    InvalidValue,
+    /// Contenu jouable mais suspect (zone inaccessible, sortie sans retour...)
+    Suspicious,
+}
+
+/// Problème détecté dans les fichiers de données
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DataIssue {
+    pub file: &'static str,    // Fichier concerné (ex: "zones.json")
+    pub entity: &'static str,  // Type d'entité (ex: "zone")
+    pub entity_id: u32,
+    pub field: &'static str,   // Champ fautif (ex: "npcs")
+    pub kind: IssueKind,
+    pub message: String,
+}
+
+impl fmt::Display for DataIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} : {} {}, champ '{}' : {}",
+               self.file, self.entity, self.entity_id, self.field, self.message)
+    }
+}
+
+/// Contenu des fichiers JSON avant la résolution des références par ID
+#[derive(Debug, Clone)]
+pub struct RawData {
+    pub classes: Vec<ClassProfile>,
+    pub levels: LevelCurve,
+    pub items: Vec<Item>,
+    pub quests: Vec<Quest>,
+    pub npcs: Vec<NpcRaw>,
+    pub monsters: Vec<MonsterRaw>,
+    pub zones: Vec<ZoneRaw>,
+}
+
+/// Données du jeu prêtes à l'emploi
+#[derive(Debug, Clone)]
+pub struct GameData {
+    pub classes: Vec<ClassProfile>,
+    pub levels: LevelCurve,
+    pub items: Vec<Item>,
+    pub quests: Vec<Quest>,
+    pub npcs: Vec<Npc>,
+    pub monsters: Vec<Monster>,
+    pub zones: HashMap<u32, Zone>,
+}
+
+/// Charge tous les fichiers JSON du dossier `dir` sans les résoudre
+pub fn load_raw_data(dir: &str) -> Result<RawData, GameError> {
+    Ok(RawData {
+        classes: read_json(&format!("{}/attributes.json", dir))?,
+        levels: read_json(&format!("{}/levels.json", dir))?,
+        items: read_json(&format!("{}/items.json", dir))?,
+        quests: read_json(&format!("{}/quests.json", dir))?,
+        npcs: read_json(&format!("{}/npcs.json", dir))?,
+        monsters: read_json(&format!("{}/monsters.json", dir))?,
+        zones: read_json(&format!("{}/zones.json", dir))?,
+    })
+}
+
+/// Charge, valide puis résout les données du dossier `dir`.
+/// En mode `Lenient`, les problèmes détectés sont renvoyés avec les données.
+pub fn load_game_data(dir: &str, mode: ValidationMode) -> Result<(GameData, Vec<DataIssue>), GameError> {
+    let raw = load_raw_data(dir)?;
+    let issues = raw.validate();
+
+    if mode == ValidationMode::Strict && !issues.is_empty() {
+        return Err(GameError::InvalidData(issues));
+    }
+
+    Ok((raw.resolve(), issues))
+}
+
+impl RawData {
+    /// Vérifie que chaque ID référencé existe bel et bien
+    pub fn validate(&self) -> Vec<DataIssue> {
+        let mut issues = Vec::new();
+
+        let item_exists = |id: u32| self.items.iter().any(|item| item.id == id);
+
+        for zone in &self.zones {
+            let mut issue = |kind, field, message| issues.push(DataIssue {
+                file: "zones.json",
+                entity: "zone",
+                entity_id: zone.id,
+                field,
+                kind,
+                message,
+            });
+
+            let mut directions = Vec::new();
+            for connection in &zone.connections {
+                match Direction::parse(&connection.direction) {
+                    None => issue(IssueKind::InvalidValue, "connections", format!("direction inconnue '{}'", connection.direction)),
+                    Some(direction) if directions.contains(&direction) => {
+                        issue(IssueKind::InvalidValue, "connections", format!("direction {:?} déclarée plusieurs fois", direction));
+                    }
+                    Some(direction) => directions.push(direction),
+                }
+                if !self.zones.iter().any(|z| z.id == connection.to) {
+                    issue(IssueKind::MissingReference, "connections", format!("zone de destination {} introuvable", connection.to));
+                }
+            }
+            for npc_id in &zone.npcs {
+                if !self.npcs.iter().any(|npc| npc.id == *npc_id) {
+                    issue(IssueKind::MissingReference, "npcs", format!("PNJ {} introuvable", npc_id));
+                }
+            }
+            for spawn in zone.monsters.iter().flatten() {
+                if !self.monsters.iter().any(|monster| monster.id == spawn.id()) {
+                    issue(IssueKind::MissingReference, "monsters", format!("monstre {} introuvable", spawn.id()));
+                }
+            }
+            if zone.ambush > 100 {
+                issue(IssueKind::InvalidValue, "ambush", format!("probabilité d'embuscade {} supérieure à 100", zone.ambush));
+            }
+            for (i, encounter) in zone.encounters.iter().enumerate() {
+                // Les monstres vaincus sont sauvegardés par nom de groupe
+                if zone.encounters[..i].iter().any(|autre| autre.name == encounter.name) {
+                    issue(IssueKind::InvalidValue, "encounters", format!("groupe '{}' déclaré plusieurs fois", encounter.name));
+                }
+                if encounter.monsters.is_empty() {
+                    issue(IssueKind::InvalidValue, "encounters", format!("groupe '{}' sans monstre", encounter.name));
+                }
+                for spawn in &encounter.monsters {
+                    if !self.monsters.iter().any(|monster| monster.id == spawn.id()) {
+                        issue(IssueKind::MissingReference, "encounters", format!("monstre {} introuvable", spawn.id()));
+                    }
+                }
+            }
+        }
+
+        for npc in &self.npcs {
+            for quest_id in &npc.quests {
+                if !self.quests.iter().any(|quest| quest.id == *quest_id) {
+                    issues.push(DataIssue {
+                        file: "npcs.json",
+                        entity: "PNJ",
+                        entity_id: npc.id,
+                        field: "quests",
+                        kind: IssueKind::MissingReference,
+                        message: format!("quête {} introuvable", quest_id),
+                    });
+                }
+            }
+
+            if let Some(tree) = &npc.dialogue_tree {
+                let mut problemes: Vec<String> = tree
+                    .broken_links()
+                    .into_iter()
+                    .map(|node| format!("nœud de dialogue '{}' introuvable", node))
+                    .collect();
+                for response in tree.responses() {
+                    for condition in &response.conditions {
+                        match condition {
+                            Condition::Objet { item_id, .. } if !item_exists(*item_id) => {
+                                problemes.push(format!("objet {} introuvable", item_id));
+                            }
+                            Condition::Quete { quest_id, .. } if !self.quests.iter().any(|q| q.id == *quest_id) => {
+                                problemes.push(format!("quête {} introuvable", quest_id));
+                            }
+                            Condition::Classe { class } if !self.classes.iter().any(|c| c.profile == *class) => {
+                                problemes.push(format!("classe '{}' absente de attributes.json", class));
+                            }
+                            _ => {}
+                        }
+                    }
+                    for effect in &response.effects {
+                        match effect {
+                            Effect::DemarrerQuete { quest_id } if !self.quests.iter().any(|q| q.id == *quest_id) => {
+                                problemes.push(format!("quête {} introuvable", quest_id));
+                            }
+                            Effect::DonnerObjet { item_id, .. } if !item_exists(*item_id) => {
+                                problemes.push(format!("objet {} introuvable", item_id));
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                for message in problemes {
+                    issues.push(DataIssue {
+                        file: "npcs.json",
+                        entity: "PNJ",
+                        entity_id: npc.id,
+                        field: "dialogue_tree",
+                        kind: IssueKind::MissingReference,
+                        message,
+                    });
+                }
+            }
+
+            if let Some(shop) = &npc.shop {
+                let mut problemes = Vec::new();
+                for entry in &shop.stock {
+                    if !item_exists(entry.item_id) {
+                        problemes.push((IssueKind::MissingReference, format!("objet {} introuvable", entry.item_id)));
+                    }
+                    if !(entry.price_multiplier.is_finite() && entry.price_multiplier > 0.0) {
+                        problemes.push((
+                            IssueKind::InvalidValue,
+                            format!("multiplicateur de prix {} invalide pour l'objet {}", entry.price_multiplier, entry.item_id),
+                        ));
+                    }
+                }
+                if !(shop.sell_multiplier.is_finite() && shop.sell_multiplier >= 0.0) {
+                    problemes.push((IssueKind::InvalidValue, format!("multiplicateur de reprise {} invalide", shop.sell_multiplier)));
+                }
+                for (kind, message) in problemes {
+                    issues.push(DataIssue {
+                        file: "npcs.json",
+                        entity: "PNJ",
+                        entity_id: npc.id,
+                        field: "shop",
+                        kind,
+                        message,
+                    });
+                }
+            }
+        }
+
+        for quest in &self.quests {
+            if let Some(item_id) = quest.objet_requis_id
+                && !item_exists(item_id)
+            {
+                issues.push(DataIssue {
+                    file: "quests.json",
+                    entity: "quête",
+                    entity_id: quest.id,
+                    field: "objet_requis_id",
+                    kind: IssueKind::MissingReference,
+                    message: format!("objet {} introuvable", item_id),
+                });
+            }
+            for objective in &quest.objectives {
+                let introuvable = match *objective {
+                    Objective::Tuer { monster_id, .. } if !self.monsters.iter().any(|m| m.id == monster_id) => {
+                        Some(format!("monstre {} introuvable", monster_id))
+                    }
+                    Objective::Atteindre { zone_id } if !self.zones.iter().any(|z| z.id == zone_id) => {
+                        Some(format!("zone {} introuvable", zone_id))
+                    }
+                    Objective::Parler { npc_id } if !self.npcs.iter().any(|n| n.id == npc_id) => {
+                        Some(format!("PNJ {} introuvable", npc_id))
+                    }
+                    Objective::Livrer { item_id, .. } if !item_exists(item_id) => {
+                        Some(format!("objet {} introuvable", item_id))
+                    }
+                    _ => None,
+                };
+                let probleme = match introuvable {
+                    Some(message) => Some((IssueKind::MissingReference, message)),
+                    None if objective.target() == 0 => {
+                        Some((IssueKind::InvalidValue, "objectif sans quantité à atteindre".to_string()))
+                    }
+                    None => None,
+                };
+                if let Some((kind, message)) = probleme {
+                    issues.push(DataIssue {
+                        file: "quests.json",
+                        entity: "quête",
+                        entity_id: quest.id,
+                        field: "objectives",
+                        kind,
+                        message,
+                    });
+                }
+            }
+            for quest_id in quest.prerequisites.quests.iter().chain(&quest.follow_ups) {
+                if !self.quests.iter().any(|q| q.id == *quest_id) {
+                    let field = if quest.follow_ups.contains(quest_id) { "follow_ups" } else { "prerequisites" };
+                    issues.push(DataIssue {
+                        file: "quests.json",
+                        entity: "quête",
+                        entity_id: quest.id,
+                        field,
+                        kind: IssueKind::MissingReference,
+                        message: format!("quête {} introuvable", quest_id),
+                    });
+                }
+            }
+            if let Some(class) = &quest.prerequisites.class
+                && !self.classes.iter().any(|c| c.profile == *class)
+            {
+                issues.push(DataIssue {
+                    file: "quests.json",
+                    entity: "quête",
+                    entity_id: quest.id,
+                    field: "prerequisites",
+                    kind: IssueKind::MissingReference,
+                    message: format!("classe '{}' absente de attributes.json", class),
+                });
+            }
+            for reward_item in &quest.reward.items {
+                if !item_exists(reward_item.id) {
+                    issues.push(DataIssue {
+                        file: "quests.json",
+                        entity: "quête",
+                        entity_id: quest.id,
+                        field: "reward",
+                        kind: IssueKind::MissingReference,
+                        message: format!("objet de récompense {} introuvable", reward_item.id),
+                    });
+                }
+            }
+        }
+
+        for item in &self.items {
+            for message in item.statuses.iter().flat_map(|application| application.problems()) {
+                issues.push(DataIssue {
+                    file: "items.json",
+                    entity: "objet",
+                    entity_id: item.id,
+                    field: "statuses",
+                    kind: IssueKind::InvalidValue,
+                    message,
+                });
+            }
+        }
+
+        // Les classes n'ont pas d'ID : on les désigne par leur position dans attributes.json, et par leur profil dans le message
+        for (i, class) in self.classes.iter().enumerate() {
+            for message in class.abilities.iter().flat_map(|ability| ability.problems()) {
+                issues.push(DataIssue {
+                    file: "attributes.json",
+                    entity: "classe",
+                    entity_id: i as u32 + 1,
+                    field: "abilities",
+                    kind: IssueKind::InvalidValue,
+                    message: format!("profil {}, {}", class.profile, message),
+                });
+            }
+        }
+
+        for (i, pair) in self.levels.thresholds.windows(2).enumerate() {
+            if pair[1] <= pair[0] {
+                issues.push(DataIssue {
+                    file: "levels.json",
+                    entity: "niveau",
+                    entity_id: i as u32 + 2,
+                    field: "experience",
+                    kind: IssueKind::InvalidValue,
+                    message: format!("le seuil {} n'est pas supérieur au précédent ({})", pair[1], pair[0]),
+                });
+            }
+        }
+
+        for monster in &self.monsters {
+            if monster.gold.min > monster.gold.max {
+                issues.push(DataIssue {
+                    file: "monsters.json",
+                    entity: "monstre",
+                    entity_id: monster.id,
+                    field: "gold",
+                    kind: IssueKind::InvalidValue,
+                    message: format!("fourchette d'or inversée ({} > {})", monster.gold.min, monster.gold.max),
+                });
+            }
+            for message in monster.on_hit.iter().flat_map(|application| application.problems()) {
+                issues.push(DataIssue {
+                    file: "monsters.json",
+                    entity: "monstre",
+                    entity_id: monster.id,
+                    field: "on_hit",
+                    kind: IssueKind::InvalidValue,
+                    message,
+                });
+            }
+            for entry in &monster.loot {
+                let introuvables = entry
+                    .item_ids()
+                    .into_iter()
+                    .filter(|item_id| !item_exists(*item_id))
+                    .map(|item_id| (IssueKind::MissingReference, format!("objet {} introuvable", item_id)));
+                let incoherences = entry.problems().into_iter().map(|message| (IssueKind::InvalidValue, message));
+                for (kind, message) in incoherences.chain(introuvables) {
+                    issues.push(DataIssue {
+                        file: "monsters.json",
+                        entity: "monstre",
+                        entity_id: monster.id,
+                        field: "loot",
+                        kind,
+                        message,
+                    });
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// Construit les entités du jeu en résolvant les références par ID
+    pub fn resolve(self) -> GameData {
+        let npcs = resolve_npcs(self.npcs, &self.quests);
+        let monsters = resolve_monsters(self.monsters);
+        let zones = resolve_zones(self.zones, &npcs, &monsters);
+
+        GameData {
+            classes: self.classes,
+            levels: self.levels,
+            items: self.items,
+            quests: self.quests,
+            npcs,
+            monsters,
+            zones,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::GoldRange;
+    use crate::quest::RewardItem;
+    use crate::zone::ConnectionRaw;
+
+    fn donnees_valides() -> RawData {
+        let items = serde_json::from_str(r#"[
+            {"id": 1, "name": "Potion", "description": "", "value": 10, "item_type": "Consommable",
+             "utilisable": true, "health": 20, "strength": 0, "defense": 0, "agility": 0}
+        ]"#).unwrap();
+
+        RawData {
+            classes: vec![],
+            levels: LevelCurve::new(vec![0, 100]),
+            items,
+            quests: vec![Quest::new(1, "Potion".into(), "Apporter une potion".into(), Some(1))],
+            npcs: vec![NpcRaw {
+                id: 1,
+                name: "Alchimiste".into(),
+                description: "".into(),
+                dialogues: vec![],
+                quests: vec![1],
+                dialogue_tree: None,
+                shop: None,
+            }],
+            monsters: vec![MonsterRaw {
+                id: 1,
+                name: "Rat".into(),
+                description: "".into(),
+                health: 5,
+                strength: 1,
+                defense: 0,
+                agility: 1,
+                loot: vec![1.into()],
+                experience: 1,
+                gold: Default::default(),
+                on_hit: vec![],
+            }],
+            zones: vec![
+                ZoneRaw {
+                    id: 1,
+                    name: "Cave".into(),
+                    description: "".into(),
+                    connections: vec![ConnectionRaw { direction: "North".into(), to: 2 }],
+                    npcs: vec![1],
+                    monsters: Some(vec![1.into()]),
+                    respawn: 0,
+                    encounters: vec![],
+                    ambush: 0,
+                },
+                ZoneRaw {
+                    id: 2,
+                    name: "Grenier".into(),
+                    description: "".into(),
+                    connections: vec![ConnectionRaw { direction: "South".into(), to: 1 }],
+                    npcs: vec![],
+                    monsters: None,
+                    respawn: 0,
+                    encounters: vec![],
+                    ambush: 0,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_donnees_valides_sans_probleme() {
+        assert!(donnees_valides().validate().is_empty());
+    }
+
+    #[test]
+    fn test_references_cassees_signalees() {
+        let mut raw = donnees_valides();
+        raw.zones[0].npcs.push(99);
+        raw.zones[0].monsters = Some(vec![1.into(), 98.into()]);
+        raw.zones[1].connections.push(ConnectionRaw { direction: "Est".into(), to: 42 });
+        raw.npcs[0].quests.push(7);
+        raw.quests[0].objet_requis_id = Some(55);
+        raw.monsters[0].loot.push(66.into());
+        raw.quests[0].reward.items.push(RewardItem { id: 77, quantity: 1 });
+        raw.quests[0].objectives.push(Objective::Tuer { monster_id: 88, count: 2 });
+        raw.quests[0].objectives.push(Objective::Parler { npc_id: 1 });
+        raw.quests[0].prerequisites.class = Some("Nécromancien".into());
+        raw.quests[0].follow_ups.push(12);
+
+        let issues = raw.validate();
+        let champs: Vec<(&str, u32, &str)> = issues
+            .iter()
+            .map(|issue| (issue.file, issue.entity_id, issue.field))
+            .collect();
+
+        assert_eq!(issues.len(), 10);
+        assert!(champs.contains(&("zones.json", 1, "npcs")));
+        assert!(champs.contains(&("zones.json", 1, "monsters")));
+        assert!(champs.contains(&("zones.json", 2, "connections")));
+        assert!(champs.contains(&("npcs.json", 1, "quests")));
+        assert!(champs.contains(&("quests.json", 1, "objet_requis_id")));
+        assert!(champs.contains(&("monsters.json", 1, "loot")));
+        assert!(champs.contains(&("quests.json", 1, "reward")));
+        assert!(champs.contains(&("quests.json", 1, "objectives")));
+        assert!(champs.contains(&("quests.json", 1, "prerequisites")));
+        assert!(champs.contains(&("quests.json", 1, "follow_ups")));
+    }
+
+    #[test]
+    fn test_direction_inconnue_signalee() {
+        let mut raw = donnees_valides();
+        raw.zones[1].connections[0].direction = "Haut".into();
+
+        let issues = raw.validate();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].to_string().contains("Haut"));
+    }
+
+    #[test]
+    fn test_direction_en_double_signalee() {
+        let mut raw = donnees_valides();
+        raw.zones[1].connections.push(ConnectionRaw { direction: "Sud".into(), to: 1 });
+
+        let issues = raw.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, IssueKind::InvalidValue);
+        assert!(issues[0].message.contains("South déclarée plusieurs fois"));
+    }
+
+    #[test]
+    fn test_arbre_de_dialogue_verifie() {
+        let mut raw = donnees_valides();
+        raw.npcs[0].dialogue_tree = Some(serde_json::from_str(r#"{
+            "start": "accueil",
+            "nodes": [{"id": "accueil", "text": "Bonjour", "responses": [
+                {"text": "Suite", "next": "perdu", "conditions": [{"type": "objet", "item_id": 40}]},
+                {"text": "Quête", "effects": [{"type": "demarrer_quete", "quest_id": 41}]},
+                {"text": "Au revoir", "conditions": [{"type": "quete", "quest_id": 1}]}
+            ]}]
+        }"#).unwrap());
+
+        let messages: Vec<String> = raw.validate().into_iter().map(|issue| issue.message).collect();
+        assert_eq!(messages.len(), 3);
+        assert!(messages.contains(&"nœud de dialogue 'perdu' introuvable".to_string()));
+        assert!(messages.contains(&"objet 40 introuvable".to_string()));
+        assert!(messages.contains(&"quête 41 introuvable".to_string()));
+    }
+
+    #[test]
+    fn test_boutique_verifiee() {
+        let mut raw = donnees_valides();
+        raw.npcs[0].shop = Some(serde_json::from_str(r#"{
+            "stock": [{"item_id": 1, "quantity": 3}, {"item_id": 50, "quantity": 1, "price_multiplier": 0}]
+        }"#).unwrap());
+
+        let issues = raw.validate();
+        assert_eq!(issues.len(), 2);
+        assert!(issues.iter().all(|issue| issue.field == "shop"));
+        assert!(issues.iter().any(|issue| issue.message == "objet 50 introuvable"));
+
+        // 1e39 dépasse la capacité d'un f32 : le multiplicateur devient infini
+        raw.npcs[0].shop = Some(serde_json::from_str(r#"{
+            "stock": [{"item_id": 1, "quantity": 3, "price_multiplier": 1e39}], "sell_multiplier": -1e39
+        }"#).unwrap());
+        let messages: Vec<String> = raw.validate().into_iter().map(|issue| issue.message).collect();
+        assert_eq!(messages, vec![
+            "multiplicateur de prix inf invalide pour l'objet 1".to_string(),
+            "multiplicateur de reprise -inf invalide".to_string(),
+        ]);
+    }
 
-    Ok(zones)
-}
\ No newline at end of file
+    #[test]
+    fn test_competences_verifiees() {
+        let mut raw = donnees_valides();
+        raw.classes = serde_json::from_str(r#"[
+            {"profile": "Mage", "stats": {"health": 80}, "abilities": [
+                {"name": "Boule de Feu", "cost": 15, "effect": {"type": "degats", "multiplier": 2.5}},
+                {"name": "Soin Raté", "cost": 5, "effect": {"type": "soin", "amount": 0}}
+            ]}
+        ]"#).unwrap();
+
+        let issues = raw.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].file, issues[0].entity_id, issues[0].field), ("attributes.json", 1, "abilities"));
+        assert_eq!(issues[0].message, "profil Mage, 'Soin Raté' : soin 0 invalide");
+    }
+
+    #[test]
+    fn test_alterations_verifiees() {
+        let mut raw = donnees_valides();
+        raw.monsters[0].on_hit = serde_json::from_str(r#"[
+            {"kind": "poison", "duration": 3, "potency": 2, "chance": 30},
+            {"kind": "saignement", "duration": 0, "potency": 2}
+        ]"#).unwrap();
+        raw.items[0].statuses = serde_json::from_str(r#"[{"kind": "renforcement", "duration": 4, "chance": 120}]"#).unwrap();
+
+        let champs: Vec<(&str, &str)> = raw.validate().iter().map(|issue| (issue.file, issue.field)).collect();
+        assert_eq!(champs, vec![("items.json", "statuses"), ("items.json", "statuses"), ("monsters.json", "on_hit")]);
+    }
+
+    #[test]
+    fn test_groupes_de_rencontre_verifies() {
+        let mut raw = donnees_valides();
+        raw.zones[1].encounters = serde_json::from_str(r#"[
+            {"name": "Meute", "monsters": [1, 1, 77]},
+            {"name": "Vide", "monsters": []},
+            {"name": "Meute", "monsters": [1]}
+        ]"#).unwrap();
+        raw.zones[1].ambush = 150;
+
+        let messages: Vec<String> = raw.validate().into_iter().map(|issue| issue.message).collect();
+        assert_eq!(messages, vec![
+            "probabilité d'embuscade 150 supérieure à 100".to_string(),
+            "monstre 77 introuvable".to_string(),
+            "groupe 'Vide' sans monstre".to_string(),
+            "groupe 'Meute' déclaré plusieurs fois".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn test_courbe_de_niveaux_croissante() {
+        let mut raw = donnees_valides();
+        raw.levels = LevelCurve::new(vec![0, 100, 100, 300]);
+
+        let issues = raw.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].file, issues[0].entity_id), ("levels.json", 3));
+    }
+
+    #[test]
+    fn test_fourchette_d_or_inversee() {
+        let mut raw = donnees_valides();
+        raw.monsters[0].gold = GoldRange::new(10, 2);
+
+        let issues = raw.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].file, issues[0].field), ("monsters.json", "gold"));
+    }
+
+    #[test]
+    fn test_resolution_des_references() {
+        let data = donnees_valides().resolve();
+        let zone = &data.zones[&1];
+        assert_eq!(zone.npcs.len(), 1);
+        assert_eq!(zone.npcs[0].quests.len(), 1);
+        assert_eq!(zone.monsters.len(), 1);
+    }
+
+    #[test]
+    fn test_fichier_absent() {
+        let err = load_items("data/inexistant.json").unwrap_err();
+        assert!(matches!(err, GameError::MissingFile { path } if path == "data/inexistant.json"));
+    }
+
+    #[test]
+    fn test_json_mal_forme() {
+        let path = std::env::temp_dir().join(format!("rpg_items_invalides_{}.json", std::process::id()));
+        fs::write(&path, "[{\"id\": 1,").unwrap();
+        let err = load_items(path.to_str().unwrap()).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(err, GameError::Parse { .. }));
+    }
+
+    #[test]
+    fn test_donnees_du_jeu_coherentes() {
+        let raw = load_raw_data("data").expect("Chargement des données");
+        assert_eq!(raw.validate(), vec![]);
+    }
+}
@@ -0,0 +1,175 @@
+use crate::player::Player;
+use crate::quest::QuestStatus;
+use serde::{Deserialize, Serialize};
+
+/// Arbre de dialogue d'un PNJ, déclaré dans npcs.json
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DialogueTree {
+    /// Nœud par lequel commence chaque conversation
+    pub start: String,
+    pub nodes: Vec<DialogueNode>,
+}
+
+/// Réplique du PNJ et réponses possibles du joueur
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DialogueNode {
+    pub id: String,
+    pub text: String,
+    /// Sans réponse, la conversation s'arrête après la réplique
+    #[serde(default)]
+    pub responses: Vec<DialogueResponse>,
+}
+
+/// Réponse numérotée proposée au joueur
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DialogueResponse {
+    pub text: String,
+    /// Nœud suivant ; `None` termine la conversation
+    #[serde(default)]
+    pub next: Option<String>,
+    /// Toutes doivent être remplies pour que la réponse soit proposée
+    #[serde(default)]
+    pub conditions: Vec<Condition>,
+    /// Appliqués quand le joueur choisit la réponse
+    #[serde(default)]
+    pub effects: Vec<Effect>,
+}
+
+/// Condition d'affichage d'une réponse, ex: `{ "type": "objet", "item_id": 4 }`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Condition {
+    /// Le joueur possède au moins `quantity` exemplaires de l'objet
+    Objet {
+        item_id: u32,
+        #[serde(default = "quantite_unitaire")]
+        quantity: u32,
+    },
+    /// La quête est à cette étape ; sans `status`, elle n'a jamais été acceptée
+    Quete {
+        quest_id: u32,
+        #[serde(default)]
+        status: Option<QuestStatus>,
+    },
+    /// Le joueur possède au moins `min` pièces d'or
+    Or { min: u32 },
+    /// Le joueur est de cette classe (nom de attributes.json)
+    Classe { class: String },
+}
+
+fn quantite_unitaire() -> u32 {
+    1
+}
+
+impl Condition {
+    pub fn is_met(&self, player: &Player) -> bool {
+        match self {
+            Condition::Objet { item_id, quantity } => player.count_items(*item_id) >= *quantity,
+            Condition::Quete { quest_id, status } => player.journal.status(*quest_id) == *status,
+            Condition::Or { min } => player.gold >= *min,
+            Condition::Classe { class } => player.classe == *class,
+        }
+    }
+}
+
+/// Conséquence d'une réponse, ex: `{ "type": "demarrer_quete", "quest_id": 2 }`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Effect {
+    /// Ajoute la quête au journal, sans autre confirmation
+    DemarrerQuete { quest_id: u32 },
+    /// Donne des objets au joueur
+    DonnerObjet {
+        item_id: u32,
+        #[serde(default = "quantite_unitaire")]
+        quantity: u32,
+    },
+    /// Ouvre la boutique du PNJ
+    OuvrirBoutique,
+}
+
+impl DialogueTree {
+    pub fn node(&self, id: &str) -> Option<&DialogueNode> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Nœuds référencés (départ ou `next`) mais absents de l'arbre
+    pub fn broken_links(&self) -> Vec<String> {
+        std::iter::once(&self.start)
+            .chain(self.nodes.iter().flat_map(|node| node.responses.iter().filter_map(|r| r.next.as_ref())))
+            .filter(|id| self.node(id).is_none())
+            .cloned()
+            .collect()
+    }
+
+    /// Toutes les réponses de l'arbre, pour vérifier leurs références
+    pub fn responses(&self) -> impl Iterator<Item = &DialogueResponse> {
+        self.nodes.iter().flat_map(|node| node.responses.iter())
+    }
+}
+
+impl DialogueNode {
+    /// Réponses dont les conditions sont remplies, dans l'ordre des données
+    pub fn available_responses(&self, player: &Player) -> Vec<&DialogueResponse> {
+        self.responses
+            .iter()
+            .filter(|response| response.conditions.iter().all(|condition| condition.is_met(player)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::Attributes;
+
+    fn arbre() -> DialogueTree {
+        serde_json::from_str(r#"{
+            "start": "accueil",
+            "nodes": [
+                {"id": "accueil", "text": "Bonjour !", "responses": [
+                    {"text": "Une quête ?", "next": "quete", "conditions": [{"type": "quete", "quest_id": 2}]},
+                    {"text": "Riche ?", "conditions": [{"type": "or", "min": 50}]},
+                    {"text": "Paladin !", "conditions": [{"type": "classe", "class": "Paladin"}],
+                     "effects": [{"type": "donner_objet", "item_id": 1, "quantity": 2}]},
+                    {"text": "Au revoir."}
+                ]},
+                {"id": "quete", "text": "Retrouve ma clé.", "responses": [
+                    {"text": "J'accepte.", "effects": [{"type": "demarrer_quete", "quest_id": 2}, {"type": "ouvrir_boutique"}]}
+                ]}
+            ]
+        }"#).unwrap()
+    }
+
+    #[test]
+    fn test_arbre_depuis_json() {
+        let arbre = arbre();
+        assert!(arbre.broken_links().is_empty());
+        let quete = arbre.node("quete").unwrap();
+        assert_eq!(quete.responses[0].effects, vec![Effect::DemarrerQuete { quest_id: 2 }, Effect::OuvrirBoutique]);
+        assert_eq!(arbre.node("accueil").unwrap().responses[2].effects[0], Effect::DonnerObjet { item_id: 1, quantity: 2 });
+    }
+
+    #[test]
+    fn test_reponses_selon_conditions() {
+        let arbre = arbre();
+        let accueil = arbre.node("accueil").unwrap();
+        let mut player = Player::new("Testeur".into(), Attributes::new(10, 1, 1, 1));
+        let textes = |player: &Player| accueil.available_responses(player).iter().map(|r| r.text.clone()).collect::<Vec<_>>();
+
+        assert_eq!(textes(&player), vec!["Une quête ?", "Au revoir."]);
+
+        player.add_gold(50);
+        player.classe = "Paladin".into();
+        player.journal.accept(2, Default::default());
+        assert_eq!(textes(&player), vec!["Riche ?", "Paladin !", "Au revoir."]);
+    }
+
+    #[test]
+    fn test_liens_casses() {
+        let mut arbre = arbre();
+        arbre.start = "debut".into();
+        arbre.nodes[1].responses[0].next = Some("fin".into());
+        assert_eq!(arbre.broken_links(), vec!["debut".to_string(), "fin".to_string()]);
+    }
+}
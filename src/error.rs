@@ -0,0 +1,122 @@
+use crate::data_loader::DataIssue;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Erreurs du chargement des données et du système de sauvegarde
+#[derive(Debug)]
+pub enum GameError {
+    /// Le fichier n'existe pas
+    MissingFile { path: String },
+    /// Le fichier existe mais n'a pas pu être lu ou écrit
+    Io { path: String, source: io::Error },
+    /// Le contenu du fichier n'est pas un JSON valide pour ce format
+    Parse { path: String, source: serde_json::Error },
+    /// Les données n'ont pas pu être converties en JSON
+    Serialize { path: String, source: serde_json::Error },
+    /// Des références entre fichiers sont cassées (mode strict)
+    InvalidData(Vec<DataIssue>),
+    /// Une entité attendue n'existe pas dans les données
+    UnknownEntity { file: String, entity: &'static str, id: String },
+    /// Option de ligne de commande non reconnue ou incomplète
+    InvalidArgument(String),
+    /// Le joueur a fermé l'entrée (fin de fichier, script épuisé)
+    InputClosed,
+}
+
+impl GameError {
+    /// Construit l'erreur adaptée à un échec d'entrée/sortie sur `path`
+    pub fn io(path: &str, source: io::Error) -> Self {
+        if source.kind() == io::ErrorKind::NotFound {
+            GameError::MissingFile { path: path.to_string() }
+        } else {
+            GameError::Io { path: path.to_string(), source }
+        }
+    }
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::MissingFile { path } => {
+                write!(f, "fichier introuvable : {} (vérifiez le dossier de données)", path)
+            }
+            GameError::Io { path, source } => write!(f, "accès impossible à {} : {}", path, source),
+            GameError::Parse { path, source } => {
+                write!(f, "JSON invalide dans {} (ligne {}, colonne {}) : {}",
+                       path, source.line(), source.column(), source)
+            }
+            GameError::Serialize { path, source } => {
+                write!(f, "sérialisation impossible pour {} : {}", path, source)
+            }
+            GameError::InvalidData(issues) => {
+                write!(f, "{} problème(s) dans les données :", issues.len())?;
+                for issue in issues {
+                    write!(f, "\n  - {}", issue)?;
+                }
+                Ok(())
+            }
+            GameError::UnknownEntity { file, entity, id } => {
+                write!(f, "{} : {} '{}' introuvable", file, entity, id)
+            }
+            GameError::InvalidArgument(message) => write!(f, "argument invalide : {}", message),
+            GameError::InputClosed => write!(f, "entrée fermée"),
+        }
+    }
+}
+
+impl Error for GameError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GameError::Io { source, .. } => Some(source),
+            GameError::Parse { source, .. } | GameError::Serialize { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_loader::IssueKind;
+
+    #[test]
+    fn test_fichier_absent() {
+        let err = GameError::io("data/absent.json", io::Error::from(io::ErrorKind::NotFound));
+        assert!(matches!(&err, GameError::MissingFile { path } if path == "data/absent.json"));
+        assert!(err.to_string().contains("data/absent.json"));
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn test_autre_erreur_io() {
+        let err = GameError::io("data/items.json", io::Error::from(io::ErrorKind::PermissionDenied));
+        assert!(matches!(err, GameError::Io { .. }));
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn test_json_invalide_indique_la_position() {
+        let source = serde_json::from_str::<Vec<u32>>("[1, 2,").unwrap_err();
+        let err = GameError::Parse { path: "data/zones.json".into(), source };
+        let message = err.to_string();
+        assert!(message.contains("data/zones.json"));
+        assert!(message.contains("ligne 1"));
+    }
+
+    #[test]
+    fn test_donnees_invalides_listees() {
+        let issue = DataIssue {
+            file: "zones.json",
+            entity: "zone",
+            entity_id: 3,
+            field: "npcs",
+            kind: IssueKind::MissingReference,
+            message: "PNJ 9 introuvable".into(),
+        };
+        let err = GameError::InvalidData(vec![issue.clone(), issue]);
+        let message = err.to_string();
+        assert!(message.starts_with("2 problème(s)"));
+        assert_eq!(message.matches("PNJ 9 introuvable").count(), 2);
+    }
+}
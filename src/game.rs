@@ -1,99 +1,213 @@
 use std::collections::HashMap;
-use std::io::{self, Write};
-use crate::player::Player;
-use crate::zone::{Zone, Direction};
+use crate::ability::{scale_damage, Ability, AbilityEffect};
+use crate::player::{Player, SAVE_VERSION, START_ZONE_ID};
+use crate::zone::{Encounter, Zone, Direction};
 use crate::npc::Npc;
-use crate::item::Item;
-use crate::quest::Quest;
-use crate::monster::{Monster, CombatResult, AttackResult, calculate_damage, check_dodge, calculate_hit_chance};
-use crate::data_loader::{load_items, load_quests, load_zones, load_npcs, load_monsters};
-
+use crate::dialogue::{DialogueTree, Effect};
+use crate::quest::{Objective, Quest, QuestReward, QuestStatus};
+use crate::item::{Item, ItemType};
+use crate::loot::roll_loot;
+use crate::combat::{CombatRounds, Combatant};
+use crate::monster::{Monster, AttackResult, resolve_player_attack, resolve_monster_attack};
+use crate::config::GameConfig;
+use crate::data_loader::{load_game_data, GameData};
+use crate::progression::{ClassProfile, LevelCurve};
+use crate::error::GameError;
+use crate::rng::GameRng;
+use crate::shop::Shop;
+use crate::status::{StatusEffect, StatusTick, Statuses};
+use crate::io::GameIo;
+use crate::ecrire;
+
+/// Partie en cours : données du monde et personnage du joueur
 pub struct Game {
+    io: Box<dyn GameIo>,
+    config: GameConfig,
     player: Player,
     zones: HashMap<u32, Zone>,
-    all_npcs: Vec<Npc>,
     all_items: Vec<Item>,
-    all_quests: Vec<Quest>,
     all_monsters: Vec<Monster>,
+    all_quests: Vec<Quest>,
+    all_npcs: Vec<Npc>,
+    classes: Vec<ClassProfile>,
+    levels: LevelCurve,
 }
 
 impl Game {
-    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        println!("🔄 Initialisation du jeu...");
-
-        // Charger les données depuis les fichiers JSON
-        let all_items = load_items("data/items.json")?;
-        let all_quests = load_quests("data/quests.json")?;
-        let all_npcs = load_npcs("data/npcs.json", &all_quests)?;
-        let all_monsters = load_monsters("data/monsters.json")?;
-        let zones = load_zones("data/zones.json", &all_npcs, &all_monsters)?;
+    /// Charge les données puis charge ou crée un personnage en interrogeant le joueur
+    pub fn new(config: GameConfig, mut io: Box<dyn GameIo>) -> Result<Self, GameError> {
+        ecrire!(io, "🔄 Initialisation du jeu...");
+
+        // Charger et valider les données depuis les fichiers JSON
+        let (data, issues) = load_game_data(&config.data_dir, config.validation)?;
+        if !issues.is_empty() {
+            ecrire!(io, "⚠️  {} problème(s) détecté(s) dans les données :", issues.len());
+            for issue in &issues {
+                ecrire!(io, "   - {}", issue);
+            }
+        }
 
         // Charger ou créer un personnage
-        let player = if std::path::Path::new("data/perso_save.json").exists() {
-            println!("📁 Sauvegarde trouvée !");
-            let choix = Self::lire_input("Voulez-vous charger votre sauvegarde ? (o/n) : ");
+        let save_path = config.save_path();
+        let player = if std::path::Path::new(&save_path).exists() {
+            ecrire!(io, "📁 Sauvegarde trouvée !");
+            let choix = io.prompt("Voulez-vous charger votre sauvegarde ? (o/n) : ")?;
             if choix.to_lowercase() == "o" || choix.to_lowercase() == "oui" {
-                Player::load_character("data/perso_save.json")?
+                Player::load_character(&save_path)?
             } else {
-                Player::create_character()?
+                Player::create_character(&config, &data.classes, io.as_mut())?
             }
         } else {
-            println!("📝 Aucune sauvegarde trouvée, création d'un nouveau personnage...");
-            Player::create_character()?
+            ecrire!(io, "📝 Aucune sauvegarde trouvée, création d'un nouveau personnage...");
+            Player::create_character(&config, &data.classes, io.as_mut())?
         };
 
-        Ok(Game {
+        Self::from_parts(config, data, player, io)
+    }
+
+    /// Construit une partie à partir de données déjà chargées, sans aucune interaction
+    pub fn from_parts(config: GameConfig, data: GameData, mut player: Player, io: Box<dyn GameIo>) -> Result<Self, GameError> {
+        // Une sauvegarde peut provenir d'un autre jeu de données
+        if !data.zones.contains_key(&player.current_zone_id) {
+            return Err(GameError::UnknownEntity {
+                file: config.save_path(),
+                entity: "zone",
+                id: player.current_zone_id.to_string(),
+            });
+        }
+
+        // Une graine imposée remplace celle de la sauvegarde
+        if let Some(seed) = config.seed {
+            player.rng = GameRng::new(seed);
+        }
+
+        // Les sauvegardes antérieures au mana n'en ont pas : on le reconstitue depuis le profil de la classe,
+        // une seule fois, la sauvegarde suivante portant la version actuelle
+        if player.save_version == 0
+            && player.base_stats.mana == 0
+            && let Some(class) = data.classes.iter().find(|c| c.profile == player.classe)
+        {
+            player.base_stats.mana = class.stats.mana + class.level_up.mana * (player.level as i32 - 1);
+            player.current_mana = player.get_max_mana();
+        }
+        player.save_version = SAVE_VERSION;
+
+        let mut game = Game {
+            io,
+            config,
             player,
-            zones,
-            all_npcs,
-            all_items,
-            all_quests,
-            all_monsters,
-        })
+            zones: data.zones,
+            all_items: data.items,
+            all_monsters: data.monsters,
+            all_quests: data.quests,
+            all_npcs: data.npcs,
+            classes: data.classes,
+            levels: data.levels,
+        };
+        // Les monstres vaincus de la sauvegarde restent absents de leur zone
+        game.actualiser_zones();
+        Ok(game)
     }
 
-    pub fn run(&mut self) -> Result<(), Box<dyn std::error::Error>> {
-        println!("\n🎯 Début de l'aventure !");
-        println!("Tapez 'aide' pour voir les commandes disponibles.");
+    pub fn run(&mut self) -> Result<(), GameError> {
+        ecrire!(self.io, "\n🎯 Début de l'aventure !");
+        ecrire!(self.io, "Tapez 'aide' pour voir les commandes disponibles.");
+        ecrire!(self.io, "🎲 Graine de la partie : {} (relancez avec '--seed {}' pour la rejouer)",
+                self.player.rng.seed(), self.player.rng.seed());
 
         loop {
             // Afficher la zone actuelle
             self.afficher_zone_actuelle();
 
-            // Afficher le menu d'interactions
-            let choix = self.afficher_menu_interactions();
+            // Afficher le menu d'interactions ; une entrée fermée termine la partie
+            let choix = match self.afficher_menu_interactions() {
+                Err(GameError::InputClosed) => break,
+                choix => choix?,
+            };
 
             // Traiter le choix du joueur
-            if !self.traiter_choix(choix)? {
-                break; // Le joueur veut quitter
+            match self.traiter_choix(&choix) {
+                Ok(true) => {}
+                Ok(false) | Err(GameError::InputClosed) => break, // Le joueur veut quitter
+                Err(e) => return Err(e),
             }
 
             // Sauvegarder après chaque action
-            self.player.save_character("data/perso_save.json")?;
+            self.sauvegarder()?;
         }
 
-        println!("👋 Merci d'avoir joué ! À bientôt !");
+        ecrire!(self.io, "👋 Merci d'avoir joué ! À bientôt !");
         Ok(())
     }
 
-    fn afficher_zone_actuelle(&self) {
+    /// Sauvegarde le personnage à l'emplacement configuré
+    pub fn sauvegarder(&self) -> Result<(), GameError> {
+        self.player.save_character(&self.config.save_path())
+    }
+
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn player_mut(&mut self) -> &mut Player {
+        &mut self.player
+    }
+
+    pub fn zones(&self) -> &HashMap<u32, Zone> {
+        &self.zones
+    }
+
+    /// Zone dans laquelle se trouve le joueur
+    pub fn current_zone(&self) -> Option<&Zone> {
+        self.zones.get(&self.player.current_zone_id)
+    }
+
+    pub fn items(&self) -> &[Item] {
+        &self.all_items
+    }
+
+    pub fn monsters(&self) -> &[Monster] {
+        &self.all_monsters
+    }
+
+    pub fn quests(&self) -> &[Quest] {
+        &self.all_quests
+    }
+
+    pub fn npcs(&self) -> &[Npc] {
+        &self.all_npcs
+    }
+
+    pub fn classes(&self) -> &[ClassProfile] {
+        &self.classes
+    }
+
+    pub fn levels(&self) -> &LevelCurve {
+        &self.levels
+    }
+
+    fn afficher_zone_actuelle(&mut self) {
         if let Some(zone) = self.zones.get(&self.player.current_zone_id) {
-            println!("\n{}", "=".repeat(50));
-            zone.afficher();
-            println!("{}", "=".repeat(50));
+            ecrire!(self.io, "\n{}", "=".repeat(50));
+            zone.afficher(self.io.as_mut());
+            ecrire!(self.io, "{}", "=".repeat(50));
         } else {
-            println!("❌ Erreur : Zone {} introuvable !", self.player.current_zone_id);
+            ecrire!(self.io, "❌ Erreur : Zone {} introuvable !", self.player.current_zone_id);
         }
     }
 
-    fn afficher_menu_interactions(&self) -> String {
-        println!("\n📋 Actions disponibles :");
+    fn afficher_menu_interactions(&mut self) -> Result<String, GameError> {
+        ecrire!(self.io, "\n📋 Actions disponibles :");
         let mut compteur = 1;
 
         // Mouvements
         if let Some(zone) = self.zones.get(&self.player.current_zone_id) {
-            for direction in &zone.connections {
-                println!("  [{}] Aller vers {:?}", compteur, direction);
+            for direction in zone.directions() {
+                ecrire!(self.io, "  [{}] Aller vers {:?}", compteur, direction);
                 compteur += 1;
             }
         }
@@ -101,36 +215,84 @@ impl Game {
         // Interactions avec les NPCs
         if let Some(zone) = self.zones.get(&self.player.current_zone_id) {
             for npc in &zone.npcs {
-                println!("  [{}] Parler à {}", compteur, npc.name());
+                ecrire!(self.io, "  [{}] Parler à {}", compteur, npc.name());
                 compteur += 1;
             }
         }
 
-        // Combat avec les monstres
+        // Combat avec les monstres, seuls ou en groupe
         if let Some(zone) = self.zones.get(&self.player.current_zone_id) {
-            for monster in &zone.monsters {
-                if monster.is_alive() {
-                    println!("  [{}] Combattre {}", compteur, monster.name);
-                    compteur += 1;
-                }
+            for encounter in zone.encounters() {
+                ecrire!(self.io, "  [{}] Combattre {}", compteur, encounter.name);
+                compteur += 1;
             }
         }
 
         // Options système
-        println!("  [inv] Voir inventaire");
+        if let Some(marchand) = self.marchand_present() {
+            ecrire!(self.io, "  [boutique] Commercer avec {}", marchand.name());
+        }
+        ecrire!(self.io, "  [inv] Voir inventaire");
 
-        println!("  [stat] Voir statistiques");
+        ecrire!(self.io, "  [stat] Voir statistiques");
         //compteur += 1;
-        println!("  [aide] Aide");
-        println!("  [quit] Quitter");
+        ecrire!(self.io, "  [aide] Aide");
+        ecrire!(self.io, "  [quit] Quitter");
 
-        Self::lire_input("\n🎮 Votre choix : ")
+        self.io.prompt("\n🎮 Votre choix : ")
     }
 
-    fn traiter_choix(&mut self, choix: String) -> Result<bool, Box<dyn std::error::Error>> {
+    /// Exécute une commande du joueur (numéro d'action, 'inv', 'stat', 'aide'...).
+    /// Renvoie `false` quand le joueur quitte la partie.
+    pub fn traiter_choix(&mut self, choix: &str) -> Result<bool, GameError> {
+        // Seules les actions font avancer l'horloge du monde (boutiques, réapparition des monstres)
+        let action = self.fait_avancer_le_monde(choix);
+        if action {
+            self.player.actions += 1;
+        }
+        let resultat = self.executer_commande(choix);
+        if !action {
+            return resultat;
+        }
+
+        for nom in self.actualiser_zones() {
+            ecrire!(self.io, "🔁 {} rôde de nouveau dans les parages !", nom);
+        }
+
+        // Hors combat, chaque action compte comme un tour pour les altérations
+        if !self.player.statuses.is_empty() {
+            let tour = self.player.statuses.tick();
+            self.subir_tour_d_alterations(&tour, false);
+        }
+        resultat
+    }
+
+    /// Se déplacer, parler, combattre, utiliser un objet ou commercer.
+    /// Consulter l'aide, les stats, le journal ou l'inventaire, ou se tromper de touche, ne coûte pas de tour.
+    fn fait_avancer_le_monde(&self, choix: &str) -> bool {
+        let commande = choix.to_lowercase();
+        let numero = |texte: &str| texte.trim().parse::<usize>().ok().filter(|n| *n > 0);
+
+        if commande.starts_with("inv") {
+            return commande
+                .split_once(',')
+                .and_then(|(_, objet)| numero(objet))
+                .is_some_and(|n| n <= self.player.inventaire.len());
+        }
+        if commande.starts_with("acheter") || commande.starts_with("vendre") {
+            return self.marchand_present().is_some() && commande.split_once(',').and_then(|(_, article)| numero(article)).is_some();
+        }
+
+        let entrees = self.zones.get(&self.player.current_zone_id).map_or(0, |zone| {
+            zone.directions().count() + zone.npcs.len() + zone.encounters().len()
+        });
+        choix.parse::<usize>().is_ok_and(|n| n > 0 && n <= entrees)
+    }
+
+    fn executer_commande(&mut self, choix: &str) -> Result<bool, GameError> {
         // Vérifier les commandes spéciales d'abord
         if choix.to_lowercase().starts_with("inv") {
-            return self.gerer_commande_inventaire(&choix);
+            return self.gerer_commande_inventaire(choix);
         }
 
         if choix.to_lowercase().starts_with("stat") {
@@ -139,7 +301,15 @@ impl Game {
         }
 
         if choix.to_lowercase().starts_with("equiper") {
-            return self.gerer_commande_equiper(&choix);
+            return self.gerer_commande_equiper(choix);
+        }
+
+        if choix.to_lowercase().starts_with("abandon") {
+            return self.gerer_commande_abandon(choix);
+        }
+
+        if choix.to_lowercase().starts_with("acheter") || choix.to_lowercase().starts_with("vendre") {
+            return self.gerer_commande_commerce(choix);
         }
 
         match choix.to_lowercase().as_str() {
@@ -147,12 +317,23 @@ impl Game {
                 self.afficher_aide();
                 Ok(true)
             },
+            "quetes" | "quêtes" | "journal" => {
+                self.afficher_journal();
+                Ok(true)
+            },
+            "boutique" => {
+                match self.marchand_present() {
+                    Some(marchand) => self.afficher_boutique(&marchand),
+                    None => ecrire!(self.io, "❌ Aucun marchand ici."),
+                }
+                Ok(true)
+            },
             "quit" => Ok(false),
             _ => {
                 if let Ok(num) = choix.parse::<usize>() {
                     self.traiter_choix_numerique(num)
                 } else {
-                    println!("❌ Choix invalide. Tapez 'aide' pour voir les commandes.");
+                    ecrire!(self.io, "❌ Choix invalide. Tapez 'aide' pour voir les commandes.");
                     Ok(true)
                 }
             }
@@ -161,7 +342,7 @@ impl Game {
 
 
 
-    fn traiter_choix_numerique(&mut self, choix: usize) -> Result<bool, Box<dyn std::error::Error>> {
+    fn traiter_choix_numerique(&mut self, choix: usize) -> Result<bool, GameError> {
         let mut compteur = 1;
 
         // Collecter les données nécessaires d'abord pour éviter les conflits de borrow
@@ -170,7 +351,7 @@ impl Game {
 
         if let Some(zone) = zone_data {
             // Vérifier les mouvements
-            for direction in &zone.connections {
+            for direction in zone.directions() {
                 if compteur == choix {
                     return self.deplacer_joueur(direction);
                 }
@@ -182,16 +363,17 @@ impl Game {
                 if compteur == choix {
                     // Cloner le NPC pour éviter les problèmes de borrow
                     let npc_clone = npc.clone();
-                    self.interagir_avec_npc(&npc_clone);
+                    self.interagir_avec_npc(&npc_clone)?;
                     return Ok(true);
                 }
                 compteur += 1;
             }
 
             // Vérifier les combats avec les monstres
-            for (i, monster) in zone.monsters.iter().enumerate() {
-                if monster.is_alive() && compteur == choix {
-                    return self.combattre_monstre(i);
+            // Les monstres vaincus ne sont pas numérotés dans le menu
+            for encounter in zone.encounters() {
+                if compteur == choix {
+                    return self.combattre(&encounter.slots, false);
                 }
                 compteur += 1;
             }
@@ -213,170 +395,512 @@ impl Game {
         }
         */
         
-        println!("❌ Choix invalide.");
+        ecrire!(self.io, "❌ Choix invalide.");
         Ok(true)
     }
 
 
-    fn deplacer_joueur(&mut self, direction: &Direction) -> Result<bool, Box<dyn std::error::Error>> {
-        // Pour l'instant, implémentation simple : chaque direction mène à une zone spécifique
-        let nouvelle_zone = match (self.player.current_zone_id, direction) {
-            // Zone 1 - Place du Village
-            (1, Direction::North) => Some(2),   // Forêt du Nord
-            (1, Direction::East) => Some(11),   // Maison d'Elara
-
-            // Zone 2 - Forêt du Nord
-            (2, Direction::South) => Some(1),   // Place du Village
-            (2, Direction::East) => Some(12),   // Ferme de Tom
-
-            // Zone 11 - Maison d'Elara
-            (11, Direction::West) => Some(1),   // Place du Village
-            (11, Direction::North) => Some(12), // Ferme de Tom
-
-            // Zone 12 - Ferme de Tom
-            (12, Direction::South) => Some(11), // Maison d'Elara
-            (12, Direction::West) => Some(2),   // Forêt du Nord
-            (12, Direction::North) => Some(21), // Clairière Mystique
-
-            // Zone 21 - Clairière Mystique
-            (21, Direction::South) => Some(12), // Ferme de Tom
-
-            // Autres combinaisons invalides
-            _ => None,
-        };
+    fn deplacer_joueur(&mut self, direction: Direction) -> Result<bool, GameError> {
+        // La destination de chaque sortie est déclarée dans zones.json
+        let nouvelle_zone = self.zones
+            .get(&self.player.current_zone_id)
+            .and_then(|zone| zone.destination(direction));
 
         if let Some(zone_id) = nouvelle_zone {
             if self.zones.contains_key(&zone_id) {
                 self.player.current_zone_id = zone_id;
-                println!("🚶 Vous vous dirigez vers {:?}...", direction);
+                ecrire!(self.io, "🚶 Vous vous dirigez vers {:?}...", direction);
 
                 // Afficher le nom de la nouvelle zone
                 if let Some(zone) = self.zones.get(&zone_id) {
-                    println!("📍 Vous arrivez à : {}", zone.name);
+                    ecrire!(self.io, "📍 Vous arrivez à : {}", zone.name);
+                }
+                self.player.journal.record_zone(zone_id);
+                self.actualiser_quetes();
+
+                if let Some(encounter) = self.tenter_embuscade() {
+                    ecrire!(self.io, "⚠️ Embuscade ! {} surgit des fourrés !", encounter.name);
+                    return self.combattre(&encounter.slots, true);
                 }
             } else {
-                println!("🚫 Cette zone n'existe pas !");
+                ecrire!(self.io, "🚫 Cette zone n'existe pas !");
             }
         } else {
-            println!("🚫 Il n'y a rien dans cette direction.");
+            ecrire!(self.io, "🚫 Il n'y a rien dans cette direction.");
         }
 
         Ok(true)
     }
 
-    fn interagir_avec_npc(&mut self, npc: &Npc) {
-        println!("\n💬 {}", npc.interact());
+    /// Tire l'embuscade de la zone actuelle : un de ses combats, au hasard, selon `ambush`
+    fn tenter_embuscade(&mut self) -> Option<Encounter> {
+        let zone = self.zones.get(&self.player.current_zone_id)?;
+        let mut encounters = zone.encounters();
+        if zone.ambush == 0 || encounters.is_empty() || !self.player.rng.chance(zone.ambush) {
+            return None;
+        }
+        let index = self.player.rng.range(0, encounters.len() as u32 - 1) as usize;
+        Some(encounters.swap_remove(index))
+    }
+
+    fn interagir_avec_npc(&mut self, npc: &Npc) -> Result<(), GameError> {
+        match &npc.dialogue_tree {
+            Some(tree) => self.derouler_dialogue(npc, tree)?,
+            None => {
+                ecrire!(self.io, "\n💬 {}", npc.interact());
+                if npc.shop.is_some() {
+                    self.afficher_boutique(npc);
+                }
+            }
+        }
+
+        // La conversation compte pour les objectifs "parler" des quêtes déjà acceptées
+        self.player.journal.record_npc(npc.id);
+        self.actualiser_quetes();
 
-        // Vérifier les quêtes
-        for quest in &npc.quests {
-            if quest.completed {
-                continue; // Skip les quêtes déjà complétées
+        // Quêtes proposées d'après le journal, les prérequis et les suites débloquées
+        let quetes: Vec<Quest> = npc.offered_quests(&self.all_quests, &self.player).into_iter().cloned().collect();
+        let mut rendue = false;
+        for quest in &quetes {
+            if self.player.journal.status(quest.id).is_none() && !self.proposer_quete(npc, quest)? {
+                continue; // Proposition déclinée
             }
 
-            let mut can_complete = true;
-            let mut completion_message = String::new();
+            if !self.player.quest_objectives_met(quest) {
+                let replique = quest.dialogue.active.as_deref().unwrap_or("Alors, où en es-tu ?");
+                ecrire!(self.io, "💬 {} : '{}'", npc.name(), replique);
+                ecrire!(self.io, "ℹ️  📋 Quête '{}' en cours :", quest.name);
+                self.afficher_objectifs(quest);
+                continue;
+            }
 
-            // Vérifier les prérequis de la quête
-            if let Some(required_item_id) = quest.objet_requis_id {
-                // Chercher l'objet requis dans l'inventaire
-                if let Some(_item) = self.player.inventaire.iter().find(|item| item.id == required_item_id) {
-                    completion_message = format!("✅ Vous avez l'objet requis ! Quête '{}' terminée !", quest.name);
-                    // Dans une vraie implémentation, on marquerait la quête comme complétée
-                    // et on retirerait l'objet si nécessaire
-                } else {
-                    can_complete = false;
-                    if let Some(required_item) = self.all_items.iter().find(|item| item.id == required_item_id) {
-                        completion_message = format!("📋 Quête '{}': Apportez-moi {} pour terminer cette quête.",
-                                                     quest.name, required_item.name);
-                    } else {
-                        completion_message = format!("📋 Quête '{}': {}", quest.name, quest.description);
+            ecrire!(self.io, "ℹ️  ✅ Quête '{}' terminée !", quest.name);
+
+            // Remettre les objets à livrer au PNJ
+            for objective in quest.all_objectives() {
+                if let Objective::Livrer { item_id, quantity } = objective {
+                    let removed = self.player.remove_items(item_id, quantity);
+                    if let Some(item) = removed.first() {
+                        ecrire!(self.io, "📤 Vous donnez {} x{} à {}", item.name, removed.len(), npc.name());
                     }
                 }
-            } else {
-                // Pas d'objet requis, la quête peut être complétée automatiquement
-                completion_message = format!("✅ Quête '{}' terminée ! Merci pour votre aide !", quest.name);
             }
 
-            println!("ℹ️  {}", completion_message);
+            let replique = quest.dialogue.done.as_deref().unwrap_or("Merci pour ton aide !");
+            ecrire!(self.io, "💬 {} : '{}'", npc.name(), replique);
+            self.accorder_recompense(&quest.reward);
+            self.player.journal.set_status(quest.id, QuestStatus::Rendue);
+            rendue = true;
+        }
+        self.actualiser_quetes();
+
+        let suites = npc.offered_quests(&self.all_quests, &self.player);
+        if rendue && suites.iter().any(|q| self.player.journal.status(q.id).is_none()) {
+            ecrire!(self.io, "💡 {} a une nouvelle quête pour vous, parlez-lui à nouveau !", npc.name());
+        } else if suites.is_empty() && !rendue {
+            // Rien à proposer : le PNJ évoque la dernière quête rendue, s'il y en a une
+            let derniere = self.all_quests
+                .iter()
+                .rev()
+                .find(|q| self.player.journal.is_turned_in(q.id) && npc.gives_quest(q.id, &self.all_quests));
+            match derniere {
+                Some(quest) => {
+                    let replique = quest.dialogue.done.as_deref().unwrap_or("Merci encore pour ton aide !");
+                    ecrire!(self.io, "ℹ️  {} : '{}'", npc.name(), replique);
+                }
+                None => ecrire!(self.io, "ℹ️  {} : 'Je n'ai pas de quête pour toi en ce moment.'", npc.name()),
+            }
+        }
+        Ok(())
+    }
 
-            if can_complete && quest.objet_requis_id.is_some() {
-                // Retirer l'objet de l'inventaire si c'est une quête avec objet requis
-                if let Some(required_item_id) = quest.objet_requis_id {
-                    if let Some(pos) = self.player.inventaire.iter().position(|item| item.id == required_item_id) {
-                        let removed_item = self.player.inventaire.remove(pos);
-                        println!("📤 Vous donnez {} à {}", removed_item.name, npc.name());
+    /// Conversation à embranchements : le joueur choisit parmi les réponses
+    /// dont les conditions sont remplies jusqu'à une réponse sans suite
+    fn derouler_dialogue(&mut self, npc: &Npc, tree: &DialogueTree) -> Result<(), GameError> {
+        let mut node_id = tree.start.clone();
+        ecrire!(self.io);
+        while let Some(node) = tree.node(&node_id) {
+            ecrire!(self.io, "💬 {} : '{}'", npc.name(), node.text);
+
+            let reponses = node.available_responses(&self.player);
+            if reponses.is_empty() {
+                break;
+            }
+            for (i, reponse) in reponses.iter().enumerate() {
+                ecrire!(self.io, "  [{}] {}", i + 1, reponse.text);
+            }
 
-                        // Donner une récompense (exemple simple)
-                        println!("🎁 Récompense: 50 pièces d'or et 25 XP !");
-                    }
+            let reponse = loop {
+                let choix = self.io.prompt("Votre réponse : ")?;
+                if let Ok(index) = choix.parse::<usize>()
+                    && index >= 1 && index <= reponses.len()
+                {
+                    break reponses[index - 1];
                 }
+                ecrire!(self.io, "❌ Choix invalide, réessayez.");
+            };
+
+            for effect in &reponse.effects {
+                self.appliquer_effet(npc, effect);
+            }
+            match &reponse.next {
+                Some(next) => node_id = next.clone(),
+                None => break,
             }
         }
+        Ok(())
+    }
 
-        if npc.quests.is_empty() || npc.quests.iter().all(|q| q.completed) {
-            println!("ℹ️  {} : 'Je n'ai pas de quête pour toi en ce moment.'", npc.name());
+    fn appliquer_effet(&mut self, npc: &Npc, effect: &Effect) {
+        match *effect {
+            Effect::DemarrerQuete { quest_id } => {
+                let Some(quest) = self.all_quests.iter().find(|q| q.id == quest_id).cloned() else {
+                    return;
+                };
+                if self.player.accept_quest(&quest) {
+                    ecrire!(self.io, "📜 Nouvelle quête acceptée : '{}'", quest.name);
+                    self.actualiser_quetes();
+                }
+            }
+            Effect::DonnerObjet { item_id, quantity } => {
+                if let Some(item) = self.all_items.iter().find(|i| i.id == item_id) {
+                    ecrire!(self.io, "📦 {} vous donne {} x{}", npc.name(), item.name, quantity);
+                    for _ in 0..quantity {
+                        self.player.inventaire.push(item.clone());
+                    }
+                    self.actualiser_quetes();
+                }
+            }
+            Effect::OuvrirBoutique if npc.shop.is_some() => self.afficher_boutique(npc),
+            Effect::OuvrirBoutique => {
+                ecrire!(self.io, "🛒 {} n'a rien à vendre pour le moment.", npc.name());
+            }
         }
     }
 
-    fn afficher_inventaire(&self) {
-        println!("\n🎒 Inventaire :");
-        if self.player.inventaire.is_empty() {
-            println!("  Vide");
+    /// Présente une quête au joueur, qui l'accepte ou la décline ; renvoie true si acceptée
+    fn proposer_quete(&mut self, npc: &Npc, quest: &Quest) -> Result<bool, GameError> {
+        let replique = quest.dialogue.offer.as_deref().unwrap_or(&quest.description);
+        ecrire!(self.io, "📜 {} vous propose la quête '{}' :", npc.name(), quest.name);
+        ecrire!(self.io, "💬 '{}'", replique);
+        self.afficher_objectifs(quest);
+        if !quest.reward.is_empty() {
+            ecrire!(self.io, "   🎁 Récompense : {}", self.resume_recompense(&quest.reward));
+        }
+
+        let choix = self.io.prompt("Acceptez-vous cette quête ? (o/n) : ")?;
+        if choix.to_lowercase() == "o" || choix.to_lowercase() == "oui" {
+            self.player.accept_quest(quest);
+            ecrire!(self.io, "📜 Nouvelle quête acceptée : '{}'", quest.name);
+            self.actualiser_quetes();
+            Ok(true)
         } else {
-            for (i, item) in self.player.inventaire.iter().enumerate() {
-                println!("  [{}] {}", i + 1, item.name);
+            ecrire!(self.io, "💬 {} : 'Reviens me voir si tu changes d'avis.'", npc.name());
+            Ok(false)
+        }
+    }
+
+    /// Résumé d'une récompense sur une ligne, ex: "20 pièces d'or, 30 XP, Potion de Soin x2"
+    fn resume_recompense(&self, reward: &QuestReward) -> String {
+        let mut parties = Vec::new();
+        if reward.gold > 0 {
+            parties.push(format!("{} pièces d'or", reward.gold));
+        }
+        if reward.experience > 0 {
+            parties.push(format!("{} XP", reward.experience));
+        }
+        for reward_item in &reward.items {
+            if let Some(item) = self.all_items.iter().find(|i| i.id == reward_item.id) {
+                parties.push(format!("{} x{}", item.name, reward_item.quantity));
             }
         }
-        println!("\n💡 Utilisez 'inv' pour plus de détails ou 'inv, X' pour utiliser un objet");
+        if let Some(stats) = &reward.stats
+            && !stats.is_zero()
+        {
+            parties.push(stats.describe());
+        }
+        parties.join(", ")
     }
 
-    fn afficher_statistiques(&self) {
+    fn afficher_statistiques(&mut self) {
         let total_stats = self.player.get_total_stats();
 
-        println!("\n📊 Statistiques de {} :", self.player.nom);
-        println!("  💚 Santé: {}/{}", self.player.current_health, self.player.get_max_health());
-        println!("  ⚔️  Force: {} (base: {} + équipement: {})",
+        ecrire!(self.io, "\n📊 Statistiques de {} :", self.player.nom);
+        if self.player.classe.is_empty() {
+            ecrire!(self.io, "  ⭐ Niveau {}", self.player.level);
+        } else {
+            ecrire!(self.io, "  ⭐ {} niveau {}", self.player.classe, self.player.level);
+        }
+        match self.levels.next_threshold(self.player.level) {
+            Some(next) => ecrire!(self.io, "  ✨ Expérience: {}/{}", self.player.experience, next),
+            None => ecrire!(self.io, "  ✨ Expérience: {} (niveau maximum)", self.player.experience),
+        }
+        ecrire!(self.io, "  💚 Santé: {}/{}", self.player.current_health, self.player.get_max_health());
+        if self.player.get_max_mana() > 0 {
+            ecrire!(self.io, "  🔮 Mana: {}/{}", self.player.current_mana, self.player.get_max_mana());
+        }
+        ecrire!(self.io, "  💰 Or: {}", self.player.gold);
+        ecrire!(self.io, "  ⚔️  Force: {} (base: {} + équipement: {})",
                  total_stats.strength,
                  self.player.base_stats.strength,
                  total_stats.strength - self.player.base_stats.strength
         );
-        println!("  🛡️  Défense: {} (base: {} + équipement: {})",
+        ecrire!(self.io, "  🛡️  Défense: {} (base: {} + équipement: {})",
                  total_stats.defense,
                  self.player.base_stats.defense,
                  total_stats.defense - self.player.base_stats.defense
         );
-        println!("  💨 Agilité: {} (base: {} + équipement: {})",
+        ecrire!(self.io, "  💨 Agilité: {} (base: {} + équipement: {})",
                  total_stats.agility,
                  self.player.base_stats.agility,
                  total_stats.agility - self.player.base_stats.agility
         );
-        println!("  🗺️  Zone actuelle: {}", self.player.current_zone_id);
+        let competences = self.competences_debloquees();
+        if !competences.is_empty() {
+            let noms: Vec<&str> = competences.iter().map(|a| a.name.as_str()).collect();
+            ecrire!(self.io, "  📜 Compétences: {}", noms.join(", "));
+        }
+        if !self.player.statuses.is_empty() {
+            ecrire!(self.io, "  ✴️ Altérations: {}", self.player.statuses.describe());
+        }
+        ecrire!(self.io, "  🗺️  Zone actuelle: {}", self.player.current_zone_id);
+        ecrire!(self.io, "  🎲 Graine: {}", self.player.rng.seed());
 
         // Afficher les statistiques de monstres tués
         if !self.player.monster_kills.is_empty() {
-            println!("\n🏆 Monstres vaincus :");
+            ecrire!(self.io, "\n🏆 Monstres vaincus :");
             for (monster_id, count) in &self.player.monster_kills {
                 if let Some(monster) = self.all_monsters.iter().find(|m| m.id == *monster_id) {
-                    println!("  - {} : {} fois", monster.name, count);
+                    ecrire!(self.io, "  - {} : {} fois", monster.name, count);
                 }
             }
         }
     }
 
-    fn afficher_aide(&self) {
-        println!("\n📖 Aide :");
-        println!("  - Utilisez les numéros pour choisir une action");
-        println!("  - Déplacez-vous entre les zones avec les directions");
-        println!("  - Parlez aux NPCs pour obtenir des quêtes");
-        println!("  - Combattez les monstres pour obtenir du loot");
-        println!("  - 'inv' : Voir l'inventaire");
-        println!("  - 'inv, X' : Utiliser l'objet numéro X");
-        println!("  - 'equiper, X' : Équiper l'objet numéro X");
-        println!("  - 'quit' : Quitter le jeu");
-        println!("  - Le jeu sauvegarde automatiquement");
+    fn afficher_journal(&mut self) {
+        self.actualiser_quetes();
+        ecrire!(self.io, "\n📜 Journal de quêtes :");
+
+        let suivies: Vec<(u32, QuestStatus)> = self.player.journal
+            .entries()
+            .filter(|(_, status)| *status != QuestStatus::Rendue)
+            .collect();
+        if suivies.is_empty() {
+            ecrire!(self.io, "  Aucune quête en cours. Parlez aux PNJ pour en obtenir !");
+        }
+
+        for (i, (quest_id, status)) in suivies.into_iter().enumerate() {
+            let Some(quest) = self.all_quests.iter().find(|q| q.id == quest_id).cloned() else {
+                continue; // Quête absente de ce jeu de données
+            };
+            ecrire!(self.io, "\n  {}. [{}] {}", i + 1, status.label(), quest.name);
+            ecrire!(self.io, "     {}", quest.description);
+
+            match self.all_npcs.iter().find(|npc| npc.gives_quest(quest_id, &self.all_quests)) {
+                Some(npc) => {
+                    let zone = self.zones
+                        .values()
+                        .filter(|zone| zone.npcs.iter().any(|n| n.id == npc.id))
+                        .min_by_key(|zone| zone.id)
+                        .map(|zone| zone.name.as_str())
+                        .unwrap_or("lieu inconnu");
+                    ecrire!(self.io, "     👤 Donnée par {} ({})", npc.name, zone);
+                }
+                None => ecrire!(self.io, "     👤 Commanditaire inconnu"),
+            }
+            self.afficher_objectifs(&quest);
+        }
+
+        let rendues = self.player.journal.entries().filter(|(_, status)| *status == QuestStatus::Rendue).count();
+        if rendues > 0 {
+            ecrire!(self.io, "\n  🏅 Quêtes rendues : {}", rendues);
+        }
+    }
+
+    fn gerer_commande_abandon(&mut self, commande: &str) -> Result<bool, GameError> {
+        // Même numérotation que le journal : quêtes pas encore rendues, par ID
+        let actives: Vec<u32> = self.player.journal
+            .entries()
+            .filter(|(_, status)| *status != QuestStatus::Rendue)
+            .map(|(id, _)| id)
+            .collect();
+
+        let numero = commande.split(',').nth(1).and_then(|n| n.trim().parse::<usize>().ok());
+        let Some(quest_id) = numero.filter(|n| *n >= 1).and_then(|n| actives.get(n - 1).copied()) else {
+            ecrire!(self.io, "❌ Utilisation : 'abandon, X' où X est le numéro de la quête dans le journal ('quetes')");
+            return Ok(true);
+        };
+
+        let nom = self.all_quests
+            .iter()
+            .find(|q| q.id == quest_id)
+            .map(|q| q.name.clone())
+            .unwrap_or_else(|| format!("n°{}", quest_id));
+        let choix = self.io.prompt(&format!("Abandonner la quête '{}' ? (o/n) : ", nom))?;
+        if choix.to_lowercase() == "o" || choix.to_lowercase() == "oui" {
+            self.abandonner_quete(quest_id);
+        } else {
+            ecrire!(self.io, "👍 Vous poursuivez la quête '{}'.", nom);
+        }
+        Ok(true)
+    }
+
+    /// Retire une quête du journal ; les objets de quête à livrer retournent au commanditaire
+    fn abandonner_quete(&mut self, quest_id: u32) {
+        self.player.journal.remove(quest_id);
+        let Some(quest) = self.all_quests.iter().find(|q| q.id == quest_id).cloned() else {
+            return;
+        };
+        let donneur = self.all_npcs
+            .iter()
+            .find(|npc| npc.gives_quest(quest_id, &self.all_quests))
+            .map(|npc| npc.name.clone())
+            .unwrap_or_else(|| "son commanditaire".to_string());
+
+        for objective in quest.all_objectives() {
+            let Objective::Livrer { item_id, .. } = objective else {
+                continue;
+            };
+            let est_objet_de_quete = self.all_items
+                .iter()
+                .any(|item| item.id == item_id && item.item_type == ItemType::ObjetDeQuete);
+            if est_objet_de_quete {
+                let count = self.player.count_items(item_id);
+                let removed = self.player.remove_items(item_id, count);
+                if let Some(item) = removed.first() {
+                    ecrire!(self.io, "📦 {} x{} rendu à {}", item.name, removed.len(), donneur);
+                }
+            }
+        }
+        ecrire!(self.io, "🗑️  Quête '{}' abandonnée. {} pourra vous la proposer à nouveau.", quest.name, donneur);
+    }
+
+    /// Premier PNJ marchand de la zone actuelle
+    fn marchand_present(&self) -> Option<Npc> {
+        self.current_zone()?.npcs.iter().find(|npc| npc.shop.is_some()).cloned()
+    }
+
+    /// Met à jour le stock sauvegardé du marchand ; renvoie true s'il vient d'être réapprovisionné
+    fn actualiser_boutique(&mut self, npc_id: u32, shop: &Shop) -> bool {
+        let now = self.player.actions;
+        let ancien = self.player.shops.get(&npc_id);
+        let state = shop.state_at(ancien, now);
+        let reapprovisionne = ancien.is_some_and(|ancien| ancien.last_restock != state.last_restock);
+        self.player.shops.insert(npc_id, state);
+        reapprovisionne
+    }
+
+    fn afficher_boutique(&mut self, npc: &Npc) {
+        let Some(shop) = &npc.shop else {
+            return;
+        };
+        if self.actualiser_boutique(npc.id, shop) {
+            ecrire!(self.io, "🔄 {} a reçu de la nouvelle marchandise !", npc.name());
+        }
+
+        ecrire!(self.io, "\n🛒 Boutique de {} :", npc.name());
+        for (i, entry) in shop.stock.iter().enumerate() {
+            let Some(item) = self.all_items.iter().find(|item| item.id == entry.item_id) else {
+                continue;
+            };
+            let reste = self.player.shops[&npc.id].remaining(entry.item_id);
+            let dispo = if reste > 0 { format!("reste {}", reste) } else { "épuisé".to_string() };
+            ecrire!(self.io, "  [{}] {} - {} pièces d'or ({})", i + 1, item.name, entry.price(item), dispo);
+        }
+        ecrire!(self.io, "  💰 Votre or : {}", self.player.gold);
+        ecrire!(self.io, "  💱 {} rachète vos objets à {}% de leur valeur (sauf les objets de quête).",
+                npc.name(), (shop.sell_multiplier * 100.0).round());
+        ecrire!(self.io, "  Tapez 'acheter, X' ou 'vendre, X' (numéro de l'inventaire).");
+    }
+
+    fn gerer_commande_commerce(&mut self, commande: &str) -> Result<bool, GameError> {
+        let parts: Vec<&str> = commande.split(',').map(|s| s.trim()).collect();
+        let achat = parts[0].to_lowercase().starts_with("acheter");
+        let numero = parts.get(1).and_then(|n| n.parse::<usize>().ok()).filter(|n| *n > 0);
+        let Some(numero) = numero.filter(|_| parts.len() == 2) else {
+            ecrire!(self.io, "❌ Format invalide ! Utilisez : '{}, numéro'", if achat { "acheter" } else { "vendre" });
+            return Ok(true);
+        };
+        let Some(marchand) = self.marchand_present() else {
+            ecrire!(self.io, "❌ Aucun marchand ici.");
+            return Ok(true);
+        };
+
+        if achat {
+            self.acheter(&marchand, numero - 1);
+        } else {
+            self.vendre(&marchand, numero - 1);
+        }
+        Ok(true)
+    }
+
+    fn acheter(&mut self, npc: &Npc, index: usize) {
+        let Some(shop) = &npc.shop else {
+            return;
+        };
+        self.actualiser_boutique(npc.id, shop);
+        let Some(entry) = shop.stock.get(index) else {
+            ecrire!(self.io, "❌ Numéro d'article invalide ! Tapez 'boutique' pour voir la marchandise.");
+            return;
+        };
+        let Some(item) = self.all_items.iter().find(|item| item.id == entry.item_id).cloned() else {
+            return;
+        };
+
+        let prix = entry.price(&item);
+        if self.player.shops[&npc.id].remaining(item.id) == 0 {
+            ecrire!(self.io, "❌ {} n'a plus de {} en stock. Repassez plus tard !", npc.name(), item.name);
+        } else if !self.player.spend_gold(prix) {
+            ecrire!(self.io, "❌ Pas assez d'or : {} coûte {} pièces, vous en avez {}.", item.name, prix, self.player.gold);
+        } else {
+            if let Some(state) = self.player.shops.get_mut(&npc.id) {
+                state.take(item.id);
+            }
+            ecrire!(self.io, "🛒 Vous achetez {} pour {} pièces d'or. (💰 {})", item.name, prix, self.player.gold);
+            self.player.inventaire.push(item);
+            self.actualiser_quetes();
+        }
+    }
+
+    fn vendre(&mut self, npc: &Npc, index: usize) {
+        let Some(shop) = &npc.shop else {
+            return;
+        };
+        let Some(item) = self.player.inventaire.get(index) else {
+            ecrire!(self.io, "❌ Numéro d'objet invalide !");
+            return;
+        };
+
+        match shop.sell_price(item) {
+            None => ecrire!(self.io, "❌ {} refuse : {} est un objet de quête.", npc.name(), item.name),
+            Some(prix) => {
+                let item = self.player.inventaire.remove(index);
+                self.player.add_gold(prix);
+                ecrire!(self.io, "💰 Vous vendez {} à {} pour {} pièces d'or. (💰 {})", item.name, npc.name(), prix, self.player.gold);
+                self.actualiser_quetes();
+            }
+        }
     }
 
-    fn gerer_commande_inventaire(&mut self, commande: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    fn afficher_aide(&mut self) {
+        ecrire!(self.io, "\n📖 Aide :");
+        ecrire!(self.io, "  - Utilisez les numéros pour choisir une action");
+        ecrire!(self.io, "  - Déplacez-vous entre les zones avec les directions");
+        ecrire!(self.io, "  - Parlez aux NPCs pour obtenir des quêtes");
+        ecrire!(self.io, "  - Combattez les monstres pour obtenir du loot");
+        ecrire!(self.io, "  - 'inv' : Voir l'inventaire");
+        ecrire!(self.io, "  - 'inv, X' : Utiliser l'objet numéro X");
+        ecrire!(self.io, "  - 'equiper, X' : Équiper l'objet numéro X");
+        ecrire!(self.io, "  - 'quetes' ou 'journal' : Voir les quêtes en cours");
+        ecrire!(self.io, "  - 'abandon, X' : Abandonner la quête numéro X du journal");
+        ecrire!(self.io, "  - 'boutique' : Voir la marchandise du marchand de la zone");
+        ecrire!(self.io, "  - 'acheter, X' : Acheter l'article numéro X de la boutique");
+        ecrire!(self.io, "  - 'vendre, X' : Vendre l'objet numéro X de l'inventaire au marchand");
+        ecrire!(self.io, "  - 'quit' : Quitter le jeu");
+        ecrire!(self.io, "  - Le jeu sauvegarde automatiquement");
+    }
+
+    fn gerer_commande_inventaire(&mut self, commande: &str) -> Result<bool, GameError> {
         let parts: Vec<&str> = commande.split(',').map(|s| s.trim()).collect();
 
         if parts.len() == 1 {
@@ -388,19 +912,19 @@ impl Game {
                 if index > 0 && index <= self.player.inventaire.len() {
                     self.utiliser_objet(index - 1)?;
                 } else {
-                    println!("❌ Numéro d'objet invalide !");
+                    ecrire!(self.io, "❌ Numéro d'objet invalide !");
                 }
             } else {
-                println!("❌ Format invalide ! Utilisez : inv, numéro");
+                ecrire!(self.io, "❌ Format invalide ! Utilisez : inv, numéro");
             }
         } else {
-            println!("❌ Format invalide ! Utilisez : 'inv' ou 'inv, numéro'");
+            ecrire!(self.io, "❌ Format invalide ! Utilisez : 'inv' ou 'inv, numéro'");
         }
 
         Ok(true)
     }
 
-    fn gerer_commande_equiper(&mut self, commande: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    fn gerer_commande_equiper(&mut self, commande: &str) -> Result<bool, GameError> {
         let parts: Vec<&str> = commande.split(',').map(|s| s.trim()).collect();
 
         if parts.len() == 2 {
@@ -408,96 +932,109 @@ impl Game {
                 if index > 0 && index <= self.player.inventaire.len() {
                     self.equiper_objet(index - 1)?;
                 } else {
-                    println!("❌ Numéro d'objet invalide !");
+                    ecrire!(self.io, "❌ Numéro d'objet invalide !");
                 }
             } else {
-                println!("❌ Format invalide ! Utilisez : equiper, numéro");
+                ecrire!(self.io, "❌ Format invalide ! Utilisez : equiper, numéro");
             }
         } else {
-            println!("❌ Format invalide ! Utilisez : 'equiper, numéro'");
+            ecrire!(self.io, "❌ Format invalide ! Utilisez : 'equiper, numéro'");
         }
 
         Ok(true)
     }
 
-    fn afficher_inventaire_detaille(&self) {
-        println!("\n🎒 Inventaire détaillé :");
+    fn afficher_inventaire_detaille(&mut self) {
+        ecrire!(self.io, "\n🎒 Inventaire détaillé :");
+        ecrire!(self.io, "  💰 Or: {}", self.player.gold);
         if self.player.inventaire.is_empty() {
-            println!("  Vide");
+            ecrire!(self.io, "  Vide");
         } else {
             for (i, item) in self.player.inventaire.iter().enumerate() {
-                println!("  [{}] {} - {} [{}]",
+                ecrire!(self.io, "  [{}] {} - {} [{}]",
                          i + 1,
                          item.name,
                          item.description,
                          item.get_type_name()
                 );
                 if item.is_equipable() || item.is_consumable() {
-                    println!("      Stats: {}", item.get_stats_description());
+                    ecrire!(self.io, "      Stats: {}", item.get_stats_description());
                 }
             }
         }
 
-        println!("\n⚔️ Équipement actuel :");
+        ecrire!(self.io, "\n⚔️ Équipement actuel :");
         if let Some(ref arme) = self.player.equipment.arme {
-            println!("  Arme: {} ({})", arme.name, arme.get_stats_description());
+            ecrire!(self.io, "  Arme: {} ({})", arme.name, arme.get_stats_description());
         } else {
-            println!("  Arme: Aucune");
+            ecrire!(self.io, "  Arme: Aucune");
         }
 
         if let Some(ref armure) = self.player.equipment.armure {
-            println!("  Armure: {} ({})", armure.name, armure.get_stats_description());
+            ecrire!(self.io, "  Armure: {} ({})", armure.name, armure.get_stats_description());
         } else {
-            println!("  Armure: Aucune");
+            ecrire!(self.io, "  Armure: Aucune");
         }
 
         if let Some(ref amulette) = self.player.equipment.amulette {
-            println!("  Amulette: {} ({})", amulette.name, amulette.get_stats_description());
+            ecrire!(self.io, "  Amulette: {} ({})", amulette.name, amulette.get_stats_description());
         } else {
-            println!("  Amulette: Aucune");
+            ecrire!(self.io, "  Amulette: Aucune");
         }
     }
 
-    fn utiliser_objet(&mut self, index: usize) -> Result<(), Box<dyn std::error::Error>> {
+    /// Utilise l'objet numéro `index` de l'inventaire ; renvoie true s'il a été consommé
+    fn utiliser_objet(&mut self, index: usize) -> Result<bool, GameError> {
         let item = self.player.inventaire[index].clone();
 
         if !item.utilisable {
-            println!("❌ Cet objet ne peut pas être utilisé !");
-            return Ok(());
+            ecrire!(self.io, "❌ Cet objet ne peut pas être utilisé !");
+            return Ok(false);
         }
 
         match item.item_type {
             crate::item::ItemType::Consommable => {
-                if item.health > 0 {
-                    let old_health = self.player.current_health;
-                    self.player.heal(item.health);
-                    let healed = self.player.current_health - old_health;
-
-                    println!("🍶 Vous utilisez {} et récupérez {} points de vie !",
-                             item.name, healed);
-                    println!("💚 Santé: {}/{}",
-                             self.player.current_health,
-                             self.player.get_max_health());
+                if item.health > 0 || !item.statuses.is_empty() {
+                    if item.health > 0 {
+                        let old_health = self.player.current_health;
+                        self.player.heal(item.health);
+                        let healed = self.player.current_health - old_health;
+
+                        ecrire!(self.io, "🍶 Vous utilisez {} et récupérez {} points de vie !",
+                                 item.name, healed);
+                        ecrire!(self.io, "💚 Santé: {}/{}",
+                                 self.player.current_health,
+                                 self.player.get_max_health());
+                    } else {
+                        ecrire!(self.io, "🍶 Vous utilisez {} !", item.name);
+                    }
+                    for application in &item.statuses {
+                        if let Some(effet) = application.roll(&mut self.player.rng) {
+                            self.alterer_joueur(effet);
+                        }
+                    }
 
                     // Retirer l'objet de l'inventaire
                     self.player.inventaire.remove(index);
+                    self.actualiser_quetes();
+                    return Ok(true);
                 } else {
-                    println!("❌ Cette potion n'a aucun effet !");
+                    ecrire!(self.io, "❌ Cette potion n'a aucun effet !");
                 }
             },
             _ => {
-                println!("❌ Cet objet ne peut pas être consommé ! Essayez de l'équiper avec 'equiper, {}'", index + 1);
+                ecrire!(self.io, "❌ Cet objet ne peut pas être consommé ! Essayez de l'équiper avec 'equiper, {}'", index + 1);
             }
         }
 
-        Ok(())
+        Ok(false)
     }
 
-    fn equiper_objet(&mut self, index: usize) -> Result<(), Box<dyn std::error::Error>> {
+    fn equiper_objet(&mut self, index: usize) -> Result<(), GameError> {
         let item = self.player.inventaire[index].clone();
 
         if !item.is_equipable() {
-            println!("❌ Cet objet ne peut pas être équipé !");
+            ecrire!(self.io, "❌ Cet objet ne peut pas être équipé !");
             return Ok(());
         }
 
@@ -507,187 +1044,696 @@ impl Game {
         match item_to_equip.item_type {
             crate::item::ItemType::Arme => {
                 if let Some(old_weapon) = self.player.equipment.arme.take() {
-                    println!("🔄 Vous déséquipez {} et équipez {}", old_weapon.name, item_to_equip.name);
+                    ecrire!(self.io, "🔄 Vous déséquipez {} et équipez {}", old_weapon.name, item_to_equip.name);
                     self.player.inventaire.push(old_weapon);
                 } else {
-                    println!("⚔️  Vous équipez {}", item_to_equip.name);
+                    ecrire!(self.io, "⚔️  Vous équipez {}", item_to_equip.name);
                 }
                 self.player.equipment.arme = Some(item_to_equip);
             },
             crate::item::ItemType::Armure => {
                 if let Some(old_armor) = self.player.equipment.armure.take() {
-                    println!("🔄 Vous déséquipez {} et équipez {}", old_armor.name, item_to_equip.name);
+                    ecrire!(self.io, "🔄 Vous déséquipez {} et équipez {}", old_armor.name, item_to_equip.name);
                     self.player.inventaire.push(old_armor);
                 } else {
-                    println!("🛡️  Vous équipez {}", item_to_equip.name);
+                    ecrire!(self.io, "🛡️  Vous équipez {}", item_to_equip.name);
                 }
                 self.player.equipment.armure = Some(item_to_equip);
             },
             crate::item::ItemType::Amulette => {
                 if let Some(old_amulet) = self.player.equipment.amulette.take() {
-                    println!("🔄 Vous déséquipez {} et équipez {}", old_amulet.name, item_to_equip.name);
+                    ecrire!(self.io, "🔄 Vous déséquipez {} et équipez {}", old_amulet.name, item_to_equip.name);
                     self.player.inventaire.push(old_amulet);
                 } else {
-                    println!("💎 Vous équipez {}", item_to_equip.name);
+                    ecrire!(self.io, "💎 Vous équipez {}", item_to_equip.name);
                 }
                 self.player.equipment.amulette = Some(item_to_equip);
             },
             _ => {
                 // Remettre l'objet dans l'inventaire si ce n'est pas équipable
                 self.player.inventaire.insert(index, item_to_equip);
-                println!("❌ Cet objet ne peut pas être équipé !");
+                ecrire!(self.io, "❌ Cet objet ne peut pas être équipé !");
             }
         }
 
         // Afficher les nouvelles stats
         let total_stats = self.player.get_total_stats();
-        println!("📊 Vos stats totales: Force: {}, Défense: {}, Agilité: {}, Santé Max: {}",
+        ecrire!(self.io, "📊 Vos stats totales: Force: {}, Défense: {}, Agilité: {}, Santé Max: {}",
                  total_stats.strength, total_stats.defense, total_stats.agility, total_stats.health);
 
         Ok(())
     }
 
-    fn combattre_monstre(&mut self, monster_index: usize) -> Result<bool, Box<dyn std::error::Error>> {
-        println!("\n⚔️ ===== COMBAT ! =====");
+    /// Combat contre les monstres des emplacements `slots` de la zone actuelle.
+    /// En embuscade, les monstres plus agiles que le joueur ont un tour d'avance.
+    fn combattre(&mut self, slots: &[usize], embuscade: bool) -> Result<bool, GameError> {
+        ecrire!(self.io, "\n⚔️ ===== COMBAT ! =====");
 
         let zone_id = self.player.current_zone_id;
-        let zone = self.zones.get_mut(&zone_id).unwrap();
-        let monster = &mut zone.monsters[monster_index].clone();
-
-        println!("🥊 Vous engagez le combat contre {} !", monster.name);
-        println!("👹 {} : {}", monster.name, monster.health_bar());
-        println!("🧑‍⚔️ {} : {}/{} HP",
-                 self.player.nom,
-                 self.player.current_health,
-                 self.player.get_max_health()
-        );
+        let zone = &self.zones[&zone_id];
+        // Chaque adversaire garde son emplacement pour être retiré de la zone une fois vaincu
+        let mut ennemis: Vec<(usize, Monster)> = slots.iter().map(|&slot| (slot, zone.monsters[slot].clone())).collect();
+        let mut vaincus: Vec<Monster> = Vec::new();
+
+        if let [(_, monster)] = ennemis.as_slice() {
+            ecrire!(self.io, "🥊 Vous engagez le combat contre {} !", monster.name);
+        } else {
+            ecrire!(self.io, "🥊 Vous engagez le combat contre {} adversaires !", ennemis.len());
+        }
+        for (_, monster) in &ennemis {
+            ecrire!(self.io, "👹 {} : {}", monster.name, monster.health_bar());
+        }
+        ecrire!(self.io, "🧑‍⚔️ {} : {}", self.player.nom, self.etat_joueur());
+
+        let agilites: Vec<i32> = ennemis.iter().map(|(_, monster)| monster.with_status_modifiers().agility).collect();
+        let mut tours = CombatRounds::new(embuscade, self.player.get_combat_stats().agility, &agilites);
+        if embuscade && !tours.is_surprise() {
+            ecrire!(self.io, "👀 Vous réagissez à temps !");
+        }
+
+        // Réduction (en %) des dégâts subis jusqu'au prochain tour du joueur, via une compétence de garde
+        let mut garde = 0;
+
+        loop {
+            ecrire!(self.io, "\n{}", "─".repeat(40));
+
+            // Initiative : l'agilité décide de l'ordre du tour
+            let monstres: Vec<(usize, i32)> = ennemis
+                .iter()
+                .map(|(slot, monster)| (*slot, monster.with_status_modifiers().agility))
+                .collect();
+            let mut tour = tours.next_round(self.player.get_combat_stats().agility, &monstres, &mut self.player.rng);
+            if tour.surprise {
+                ecrire!(self.io, "⚠️ Pris par surprise, vous ne pouvez pas réagir !");
+            }
+
+            // Les altérations agissent à la première action de chacun dans le tour
+            while let Some(action) = tour.next_action() {
+                match action.combatant {
+                    Combatant::Joueur => {
+                        if action.first {
+                            garde = 0;
+                            let alterations = self.player.statuses.tick();
+                            self.subir_tour_d_alterations(&alterations, true);
+                            if !self.player.is_alive() {
+                                let vainqueur = ennemis[0].1.name.clone();
+                                self.subir_defaite(&vainqueur)?;
+                                return self.recompenser_vaincus(zone_id, &mut ennemis, &mut vaincus);
+                            }
+                            if alterations.stunned {
+                                ecrire!(self.io, "💫 Vous êtes étourdi et perdez votre tour !");
+                                tour.stun(action.combatant);
+                                continue;
+                            }
+                        } else {
+                            ecrire!(self.io, "⚡ Votre vivacité vous offre une action supplémentaire !");
+                        }
+
+                        if self.tour_du_joueur(&mut ennemis, &mut garde)? {
+                            self.player.statuses.end_combat();
+                            return self.recompenser_vaincus(zone_id, &mut ennemis, &mut vaincus);
+                        }
+                    }
+                    Combatant::Monstre(slot) => {
+                        let Some((_, monster)) = ennemis.iter_mut().find(|(s, _)| *s == slot) else {
+                            continue; // Vaincu plus tôt dans le tour
+                        };
+                        if action.first {
+                            let alterations = monster.statuses.tick();
+                            self.faire_subir_tour_d_alterations(monster, &alterations);
+                            if alterations.stunned && monster.is_alive() {
+                                ecrire!(self.io, "\n💫 {} est étourdi et ne peut pas attaquer !", monster.name);
+                                tour.stun(action.combatant);
+                            }
+                        } else {
+                            ecrire!(self.io, "\n⚡ {} est si vif qu'il attaque une seconde fois !", monster.name);
+                        }
 
+                        // Achevé par ses altérations, il rejoint les vaincus ci-dessous sans attaquer
+                        if monster.is_alive() && !tour.is_stunned(action.combatant) {
+                            let monster = monster.clone();
+                            self.attaque_du_monstre(&monster, garde);
+                            if !self.player.is_alive() {
+                                self.subir_defaite(&monster.name)?;
+                                return self.recompenser_vaincus(zone_id, &mut ennemis, &mut vaincus);
+                            }
+                        }
+                    }
+                }
+
+                // Les monstres vaincus quittent le combat
+                self.retirer_vaincus(zone_id, &mut ennemis, &mut vaincus);
+                if ennemis.is_empty() {
+                    return self.remporter_combat(&vaincus);
+                }
+            }
+
+            // Fin du tour : le mana se recharge d'un dixième
+            self.player.restore_mana((self.player.get_max_mana() / 10).max(1));
+        }
+    }
+
+    /// Action du joueur (attaque, objet, compétence) ; renvoie true s'il prend la fuite
+    fn tour_du_joueur(&mut self, ennemis: &mut [(usize, Monster)], garde: &mut u32) -> Result<bool, GameError> {
         loop {
-            println!("\n{}", "─".repeat(40));
-            println!("🎯 Votre tour !");
-            println!("  [1] Attaquer");
-            println!("  [2] Fuir");
+            ecrire!(self.io, "🎯 Votre tour !");
+            if self.player.get_max_mana() > 0 {
+                ecrire!(self.io, "🔮 Mana : {}/{}", self.player.current_mana, self.player.get_max_mana());
+            }
+            ecrire!(self.io, "  [1] Attaquer");
+            ecrire!(self.io, "  [2] Fuir");
+            ecrire!(self.io, "  [3] Utiliser un objet");
+            let competences = self.competences_debloquees();
+            if !competences.is_empty() {
+                ecrire!(self.io, "  [4] Compétence");
+            }
 
-            let choix = Self::lire_input("Votre action : ");
+            let choix = self.io.prompt("Votre action : ")?;
 
             match choix.as_str() {
                 "1" => {
+                    let Some(cible) = self.choisir_cible(ennemis)? else {
+                        continue;
+                    };
+                    let monster = &mut ennemis[cible].1;
                     // Attaque du joueur
-                    let player_stats = self.player.get_total_stats().clone();
-                    let attack_result = self.attaque_joueur(&player_stats, monster);
+                    let player_stats = self.player.get_combat_stats();
+                    let attack_result = resolve_player_attack(&player_stats, &monster.with_status_modifiers(), &mut self.player.rng);
                     match attack_result {
                         AttackResult::Hit(damage) => {
-                            println!("💥 Vous frappez {} pour {} dégâts !", monster.name, damage);
+                            ecrire!(self.io, "💥 Vous frappez {} pour {} dégâts !", monster.name, damage);
                             monster.take_damage(damage);
-                            println!("👹 {} : {}", monster.name, monster.health_bar());
+                            ecrire!(self.io, "👹 {} : {}", monster.name, monster.health_bar());
                         },
                         AttackResult::Miss => {
-                            println!("😅 Votre attaque rate sa cible !");
+                            ecrire!(self.io, "😅 Votre attaque rate sa cible !");
                         },
                         AttackResult::Dodge => {
                             // Impossible pour le joueur d'esquiver en attaquant
                         }
                     }
-
-                    // Vérifier si le monstre est vaincu
-                    if !monster.is_alive() {
-                        println!("\n🎉 Victoire ! Vous avez vaincu {} !", monster.name);
-
-                        // Ajouter le kill au compteur
-                        self.player.add_monster_kill(monster.id);
-                        return self.gerer_victoire(monster);
-                    }
+                    return Ok(false);
                 },
                 "2" => {
-                    println!("🏃 Vous fuyez le combat !");
+                    ecrire!(self.io, "🏃 Vous fuyez le combat !");
                     return Ok(true);
                 },
+                "3" => {
+                    // Boire une potion coûte le tour ; annuler permet de choisir une autre action
+                    if self.utiliser_objet_en_combat()? {
+                        return Ok(false);
+                    }
+                },
+                "4" if !competences.is_empty() => {
+                    let Some(ability) = self.choisir_competence(&competences)? else {
+                        continue;
+                    };
+                    // Soins et gardes ne visent personne
+                    let cible = if ability.is_offensive() { self.choisir_cible(ennemis)? } else { Some(0) };
+                    let Some(cible) = cible else {
+                        // Renoncer à la cible rend le mana dépensé
+                        self.player.restore_mana(ability.cost);
+                        continue;
+                    };
+                    let reduction = self.utiliser_competence(&ability, &mut ennemis[cible].1);
+                    *garde = (*garde).max(reduction);
+                    return Ok(false);
+                },
                 _ => {
-                    println!("❌ Choix invalide !");
-                    continue;
+                    ecrire!(self.io, "❌ Choix invalide !");
                 }
             }
+        }
+    }
 
-            // Tour du monstre
-            println!("\n🔥 {} attaque !", monster.name);
-            let player_stats = self.player.get_total_stats();
-            let attack_result = self.attaque_monstre(monster, &player_stats);
-            match attack_result {
-                AttackResult::Hit(damage) => {
-                    println!("💢 {} vous frappe pour {} dégâts !", monster.name, damage);
-                    self.player.take_damage(damage);
-                    println!("🧑‍⚔️ Votre santé : {}/{} HP",
-                             self.player.current_health,
-                             self.player.get_max_health()
-                    );
-                },
-                AttackResult::Dodge => {
-                    println!("💨 Vous esquivez l'attaque de {} !", monster.name);
-                },
-                AttackResult::Miss => {
-                    println!("😌 L'attaque de {} vous rate !", monster.name);
+    /// Attaque d'un monstre contre le joueur, réduite par sa garde éventuelle
+    fn attaque_du_monstre(&mut self, monster: &Monster, garde: u32) {
+        ecrire!(self.io, "\n🔥 {} attaque !", monster.name);
+        let player_stats = self.player.get_combat_stats();
+        let attack_result = resolve_monster_attack(&monster.with_status_modifiers(), &player_stats, &mut self.player.rng);
+        match attack_result {
+            AttackResult::Hit(mut damage) => {
+                if garde > 0 {
+                    let absorbe = damage * garde as i32 / 100;
+                    ecrire!(self.io, "🛡️ Votre garde absorbe {} dégâts !", absorbe);
+                    damage -= absorbe;
+                }
+                ecrire!(self.io, "💢 {} vous frappe pour {} dégâts !", monster.name, damage);
+                self.player.take_damage(damage);
+                for application in &monster.on_hit {
+                    if let Some(effet) = application.roll(&mut self.player.rng) {
+                        self.alterer_joueur(effet);
+                    }
                 }
+                ecrire!(self.io, "🧑‍⚔️ Votre santé : {}", self.etat_joueur());
+            },
+            AttackResult::Dodge => {
+                ecrire!(self.io, "💨 Vous esquivez l'attaque de {} !", monster.name);
+            },
+            AttackResult::Miss => {
+                ecrire!(self.io, "😌 L'attaque de {} vous rate !", monster.name);
+            }
+        }
+    }
+
+    /// Fait choisir l'adversaire visé ; sans question s'il n'en reste qu'un, `None` si annulé
+    fn choisir_cible(&mut self, ennemis: &[(usize, Monster)]) -> Result<Option<usize>, GameError> {
+        if ennemis.len() == 1 {
+            return Ok(Some(0));
+        }
+        ecrire!(self.io, "🎯 Cibles :");
+        for (i, (_, monster)) in ennemis.iter().enumerate() {
+            ecrire!(self.io, "  [{}] {} {}", i + 1, monster.name, monster.health_bar());
+        }
+        let choix = self.io.prompt("Cible (0 pour annuler) : ")?;
+        match choix.parse::<usize>() {
+            Ok(0) => Ok(None),
+            Ok(numero) if numero <= ennemis.len() => Ok(Some(numero - 1)),
+            _ => {
+                ecrire!(self.io, "❌ Choix invalide !");
+                Ok(None)
             }
+        }
+    }
 
-            // Vérifier si le joueur est vaincu
-            if !self.player.is_alive() {
-                println!("\n💀 Défaite ! Vous avez été vaincu par {} !", monster.name);
-                println!("🏥 Vous vous réveillez au village avec 1 HP...");
-                self.player.current_health = 1;
-                self.player.current_zone_id = 1; // Retour au village
-                return Ok(true);
+    /// Compte les monstres tombés, les retire de la zone et du combat
+    fn retirer_vaincus(&mut self, zone_id: u32, ennemis: &mut Vec<(usize, Monster)>, vaincus: &mut Vec<Monster>) {
+        let (morts, vivants): (Vec<_>, Vec<_>) = ennemis.drain(..).partition(|(_, monster)| !monster.is_alive());
+        *ennemis = vivants;
+        for (slot, monster) in morts {
+            if !ennemis.is_empty() {
+                ecrire!(self.io, "💀 {} est vaincu !", monster.name);
             }
+            self.player.add_monster_kill(monster.id);
+            self.retirer_monstre_vaincu(zone_id, slot);
+            vaincus.push(monster);
         }
     }
 
-    fn attaque_joueur(&self, player_stats: &crate::player::Attributes, monster: &Monster) -> AttackResult {
-        if calculate_hit_chance() {
-            let damage = calculate_damage(player_stats.strength, monster.defense);
-            AttackResult::Hit(damage)
+    /// Fuite ou défaite : les adversaires déjà vaincus rapportent tout de même butin, or et expérience
+    fn recompenser_vaincus(&mut self, zone_id: u32, ennemis: &mut Vec<(usize, Monster)>, vaincus: &mut Vec<Monster>) -> Result<bool, GameError> {
+        self.retirer_vaincus(zone_id, ennemis, vaincus);
+        if vaincus.is_empty() {
+            return Ok(true);
+        }
+        self.gerer_victoire(vaincus)
+    }
+
+    fn remporter_combat(&mut self, vaincus: &[Monster]) -> Result<bool, GameError> {
+        let noms: Vec<&str> = vaincus.iter().map(|monster| monster.name.as_str()).collect();
+        ecrire!(self.io, "\n🎉 Victoire ! Vous avez vaincu {} !", noms.join(", "));
+        self.player.statuses.end_combat();
+        self.gerer_victoire(vaincus)
+    }
+
+    fn subir_defaite(&mut self, vainqueur: &str) -> Result<bool, GameError> {
+        ecrire!(self.io, "\n💀 Défaite ! Vous avez été vaincu par {} !", vainqueur);
+        ecrire!(self.io, "🏥 Vous vous réveillez au village avec 1 HP...");
+        self.player.current_health = 1;
+        self.player.statuses = Statuses::default(); // Les soins du village dissipent toutes les altérations
+        self.player.current_zone_id = START_ZONE_ID; // Retour au village
+        Ok(true)
+    }
+
+    /// Santé du joueur suivie de ses altérations, ex: "42/100 HP ☠️ Poison 3 (2 tours)"
+    fn etat_joueur(&self) -> String {
+        let sante = format!("{}/{} HP", self.player.current_health, self.player.get_max_health());
+        if self.player.statuses.is_empty() {
+            sante
         } else {
-            AttackResult::Miss
+            format!("{} {}", sante, self.player.statuses.describe())
         }
     }
 
-    fn attaque_monstre(&self, monster: &Monster, player_stats: &crate::player::Attributes) -> AttackResult {
-        if calculate_hit_chance() {
-            if check_dodge(player_stats.agility) {
-                AttackResult::Dodge
-            } else {
-                let damage = calculate_damage(monster.strength, player_stats.defense);
-                AttackResult::Hit(damage)
+    /// Applique au joueur les dégâts et soins d'un tour d'altérations.
+    /// Hors combat (`mortel` à false), elles ne peuvent pas descendre sous 1 HP.
+    fn subir_tour_d_alterations(&mut self, tour: &StatusTick, mortel: bool) {
+        let damage = if mortel { tour.damage } else { tour.damage.min(self.player.current_health - 1) };
+        if damage > 0 {
+            self.player.take_damage(damage);
+            ecrire!(self.io, "🩸 Vos altérations vous infligent {} dégâts ! ({})", damage, self.etat_joueur());
+        }
+        if tour.heal > 0 && self.player.is_alive() {
+            let old_health = self.player.current_health;
+            self.player.heal(tour.heal);
+            ecrire!(self.io, "🌿 Vous récupérez {} points de vie ({}).", self.player.current_health - old_health, self.etat_joueur());
+        }
+        for kind in &tour.expired {
+            ecrire!(self.io, "{} {} se dissipe.", kind.icon(), kind.name());
+        }
+    }
+
+    fn faire_subir_tour_d_alterations(&mut self, monster: &mut Monster, tour: &StatusTick) {
+        if tour.damage > 0 {
+            monster.take_damage(tour.damage);
+            ecrire!(self.io, "🩸 {} subit {} dégâts de ses altérations !", monster.name, tour.damage);
+            if !monster.is_alive() {
+                ecrire!(self.io, "☠️ {} succombe à ses blessures !", monster.name);
             }
-        } else {
-            AttackResult::Miss
+        }
+        if tour.heal > 0 && monster.is_alive() {
+            monster.current_health = (monster.current_health + tour.heal).min(monster.max_health);
+            ecrire!(self.io, "🌿 {} récupère {} points de vie.", monster.name, tour.heal);
+        }
+        if tour.damage > 0 || tour.heal > 0 {
+            ecrire!(self.io, "👹 {} : {}", monster.name, monster.health_bar());
         }
     }
 
-    fn gerer_victoire(&mut self, monster: &Monster) -> Result<bool, Box<dyn std::error::Error>> {
-        println!("💰 Butin obtenu :");
+    fn alterer_joueur(&mut self, effet: StatusEffect) {
+        ecrire!(self.io, "✴️ Vous êtes affecté : {}", effet.describe());
+        self.player.statuses.apply(effet);
+    }
 
-        for item_id in &monster.loot {
-            if let Some(item) = self.all_items.iter().find(|i| i.id == *item_id) {
-                println!("  📦 {} - {}", item.name, item.description);
-                self.player.inventaire.push(item.clone());
+    fn alterer_monstre(&mut self, monster: &mut Monster, effet: StatusEffect) {
+        ecrire!(self.io, "✴️ {} est affecté : {}", monster.name, effet.describe());
+        monster.statuses.apply(effet);
+    }
+
+    /// Compétences de la classe du joueur débloquées à son niveau
+    fn competences_debloquees(&self) -> Vec<Ability> {
+        self.classes
+            .iter()
+            .find(|c| c.profile == self.player.classe)
+            .map(|c| c.abilities_at(self.player.level).into_iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Fait choisir une compétence et paie son coût ; `None` si annulé ou mana insuffisant
+    fn choisir_competence(&mut self, competences: &[Ability]) -> Result<Option<Ability>, GameError> {
+        ecrire!(self.io, "📜 Compétences :");
+        for (i, ability) in competences.iter().enumerate() {
+            ecrire!(self.io, "  [{}] {} ({} mana) - {}", i + 1, ability.name, ability.cost, ability.describe_effect());
+            if !ability.description.is_empty() {
+                ecrire!(self.io, "      {}", ability.description);
+            }
+        }
+        let choix = self.io.prompt("Compétence (0 pour annuler) : ")?;
+        let ability = match choix.parse::<usize>() {
+            Ok(0) => return Ok(None),
+            Ok(numero) if numero <= competences.len() => &competences[numero - 1],
+            _ => {
+                ecrire!(self.io, "❌ Choix invalide !");
+                return Ok(None);
+            }
+        };
+
+        if !self.player.spend_mana(ability.cost) {
+            ecrire!(self.io, "❌ Pas assez de mana pour {} ({} requis, vous avez {}).",
+                    ability.name, ability.cost, self.player.current_mana);
+            return Ok(None);
+        }
+        Ok(Some(ability.clone()))
+    }
+
+    /// Applique une compétence ; renvoie la réduction de dégâts accordée pour l'attaque suivante
+    fn utiliser_competence(&mut self, ability: &Ability, monster: &mut Monster) -> u32 {
+        ecrire!(self.io, "✨ Vous utilisez {} !", ability.name);
+        let mut garde = 0;
+        let (coups, multiplier) = match ability.effect {
+            AbilityEffect::Degats { multiplier } => (1, multiplier),
+            AbilityEffect::Rafale { hits, multiplier } => (hits, multiplier),
+            AbilityEffect::Soin { amount } => {
+                let old_health = self.player.current_health;
+                self.player.heal(amount);
+                ecrire!(self.io, "💚 Vous récupérez {} points de vie ({}/{}).",
+                        self.player.current_health - old_health, self.player.current_health, self.player.get_max_health());
+                (0, 0.0)
+            }
+            AbilityEffect::Garde { reduction } => {
+                ecrire!(self.io, "🛡️ Vous vous mettez en garde : -{} % de dégâts jusqu'à votre prochain tour.", reduction);
+                garde = reduction;
+                (0, 0.0)
+            }
+        };
+
+        let player_stats = self.player.get_combat_stats();
+        let cible = monster.with_status_modifiers();
+        let mut touches = 0;
+        for _ in 0..coups {
+            if !monster.is_alive() {
+                break;
+            }
+            match resolve_player_attack(&player_stats, &cible, &mut self.player.rng) {
+                AttackResult::Hit(damage) => {
+                    let damage = scale_damage(damage, multiplier);
+                    ecrire!(self.io, "💥 {} frappe {} pour {} dégâts !", ability.name, monster.name, damage);
+                    monster.take_damage(damage);
+                    touches += 1;
+                    ecrire!(self.io, "👹 {} : {}", monster.name, monster.health_bar());
+                }
+                _ => ecrire!(self.io, "😅 {} rate sa cible !", ability.name),
+            }
+        }
+
+        // Une attaque entièrement ratée n'altère pas sa cible
+        let touche = coups == 0 || touches > 0;
+        for application in &ability.statuses {
+            if application.effect.kind.is_harmful() {
+                if touche && monster.is_alive() && let Some(effet) = application.roll(&mut self.player.rng) {
+                    self.alterer_monstre(monster, effet);
+                }
+            } else if let Some(effet) = application.roll(&mut self.player.rng) {
+                self.alterer_joueur(effet);
             }
         }
+        garde
+    }
+
+    /// Propose les consommables de l'inventaire pendant un combat ; renvoie true si un objet a été utilisé
+    fn utiliser_objet_en_combat(&mut self) -> Result<bool, GameError> {
+        let consommables: Vec<usize> = self.player.inventaire
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.is_consumable() && item.utilisable)
+            .map(|(i, _)| i)
+            .collect();
+        if consommables.is_empty() {
+            ecrire!(self.io, "❌ Aucun consommable dans l'inventaire !");
+            return Ok(false);
+        }
 
-        if monster.experience > 0 {
-            println!("✨ Vous gagnez {} points d'expérience !", monster.experience);
+        ecrire!(self.io, "🎒 Consommables :");
+        for &i in &consommables {
+            let item = &self.player.inventaire[i];
+            ecrire!(self.io, "  [{}] {} ({})", i + 1, item.name, item.get_stats_description());
         }
+        let choix = self.io.prompt("Objet à utiliser (0 pour annuler) : ")?;
+        match choix.parse::<usize>() {
+            Ok(numero) if numero >= 1 && consommables.contains(&(numero - 1)) => self.utiliser_objet(numero - 1),
+            Ok(0) => Ok(false),
+            _ => {
+                ecrire!(self.io, "❌ Numéro d'objet invalide !");
+                Ok(false)
+            }
+        }
+    }
+
+    /// Le monstre vaincu quitte la zone jusqu'à sa réapparition
+    fn retirer_monstre_vaincu(&mut self, zone_id: u32, slot: usize) {
+        let Some(zone) = self.zones.get_mut(&zone_id) else {
+            return;
+        };
+        zone.monsters[slot].current_health = 0;
+        self.player.zones.entry(zone_id).or_default().record_defeat(zone.spawn_ids[slot].clone(), self.player.actions);
+    }
 
-        // Afficher les stats de kill
-        let kills = self.player.get_monster_kills(monster.id);
-        println!("🏆 Vous avez maintenant tué {} {} au total !", kills, monster.name);
+    /// Applique l'état sauvegardé des zones : les monstres dont le délai de réapparition
+    /// est écoulé reviennent en pleine santé, les autres vaincus restent absents.
+    /// Renvoie les noms des monstres revenus dans la zone actuelle.
+    fn actualiser_zones(&mut self) -> Vec<String> {
+        let now = self.player.actions;
+        let mut revenus = Vec::new();
+        for (zone_id, zone) in &mut self.zones {
+            if let Some(state) = self.player.zones.get_mut(zone_id) {
+                // Un monstre retiré de zones.json depuis la sauvegarde est oublié
+                state.defeated.retain(|defeat| {
+                    zone.slot_of(&defeat.spawn).is_some_and(|slot| {
+                        let delay = zone.respawn_delays[slot];
+                        delay == 0 || now.saturating_sub(defeat.defeated_at) < delay
+                    })
+                });
+            }
+            let state = self.player.zones.get(zone_id);
+            for (slot, monster) in zone.monsters.iter_mut().enumerate() {
+                if state.is_some_and(|state| state.is_defeated(&zone.spawn_ids[slot])) {
+                    monster.current_health = 0;
+                } else if !monster.is_alive() {
+                    monster.current_health = monster.max_health;
+                    if *zone_id == self.player.current_zone_id {
+                        revenus.push(monster.name.clone());
+                    }
+                }
+            }
+        }
+        self.player.zones.retain(|_, state| !state.defeated.is_empty());
+        revenus
+    }
 
+    /// Butin, or et expérience cumulés de tous les monstres vaincus du combat
+    fn gerer_victoire(&mut self, vaincus: &[Monster]) -> Result<bool, GameError> {
+        ecrire!(self.io, "💰 Butin obtenu :");
+
+        // Les objets identiques lâchés par plusieurs monstres sont regroupés
+        let mut butin: Vec<(u32, u32)> = Vec::new();
+        for monster in vaincus {
+            for (item_id, quantity) in roll_loot(&monster.loot, &mut self.player.rng) {
+                match butin.iter_mut().find(|(id, _)| *id == item_id) {
+                    Some((_, total)) => *total += quantity,
+                    None => butin.push((item_id, quantity)),
+                }
+            }
+        }
+        for (item_id, quantity) in butin {
+            if let Some(item) = self.all_items.iter().find(|i| i.id == item_id) {
+                if quantity > 1 {
+                    ecrire!(self.io, "  📦 {} x{} - {}", item.name, quantity, item.description);
+                } else {
+                    ecrire!(self.io, "  📦 {} - {}", item.name, item.description);
+                }
+                for _ in 0..quantity {
+                    self.player.inventaire.push(item.clone());
+                }
+            }
+        }
+
+        let gold: u32 = vaincus.iter().map(|monster| monster.gold.roll(&mut self.player.rng)).sum();
+        if gold > 0 {
+            ecrire!(self.io, "  🪙 {} pièces d'or", gold);
+            self.player.add_gold(gold);
+        }
+
+        let experience: u32 = vaincus.iter().map(|monster| monster.experience).sum();
+        if experience > 0 {
+            ecrire!(self.io, "✨ Vous gagnez {} points d'expérience !", experience);
+            self.gagner_experience(experience);
+        }
+
+        // Afficher les stats de kill, une fois par espèce
+        let mut especes: Vec<&Monster> = Vec::new();
+        for monster in vaincus {
+            if !especes.iter().any(|m| m.id == monster.id) {
+                especes.push(monster);
+            }
+        }
+        for monster in especes {
+            let kills = self.player.get_monster_kills(monster.id);
+            ecrire!(self.io, "🏆 Vous avez maintenant tué {} {} au total !", kills, monster.name);
+        }
+
+        self.actualiser_quetes();
         Ok(true)
     }
 
-    fn lire_input(msg: &str) -> String {
-        print!("{}", msg);
-        io::stdout().flush().unwrap();
-        let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
-        input.trim().to_string()
+    /// Crédite de l'expérience au joueur et annonce les niveaux gagnés
+    fn gagner_experience(&mut self, amount: u32) {
+        // Les sauvegardes d'avant les classes n'ont pas de profil : aucun gain de stats
+        let growth = self.classes
+            .iter()
+            .find(|c| c.profile == self.player.classe)
+            .map(|c| c.level_up.clone())
+            .unwrap_or_default();
+
+        let ancien_niveau = self.player.level;
+        let gained = self.player.gain_experience(amount, &self.levels, &growth);
+        if gained > 0 {
+            ecrire!(self.io, "🆙 Niveau {} atteint ! Santé restaurée ({}/{}).",
+                    self.player.level, self.player.current_health, self.player.get_max_health());
+            if !growth.is_zero() {
+                ecrire!(self.io, "   Gains : {}", growth.scaled(gained as i32).describe());
+            }
+            for ability in self.competences_debloquees().iter().filter(|a| a.level > ancien_niveau) {
+                ecrire!(self.io, "🌟 Nouvelle compétence : {} ({})", ability.name, ability.describe_effect());
+            }
+        }
+    }
+
+    /// Met à jour l'étape des quêtes suivies et annonce celles qui sont à rendre
+    fn actualiser_quetes(&mut self) {
+        let suivies: Vec<(u32, QuestStatus)> = self.player.journal
+            .entries()
+            .filter(|(_, status)| *status != QuestStatus::Rendue)
+            .collect();
+
+        for (quest_id, status) in suivies {
+            let Some(quest) = self.all_quests.iter().find(|q| q.id == quest_id) else {
+                continue; // Quête absente de ce jeu de données
+            };
+            let nouveau = if self.player.quest_objectives_met(quest) {
+                QuestStatus::Terminee
+            } else if quest.all_objectives().iter().any(|o| self.player.objective_progress(quest_id, o) > 0) {
+                QuestStatus::EnCours
+            } else {
+                QuestStatus::Acceptee
+            };
+
+            if nouveau == QuestStatus::Terminee && status != QuestStatus::Terminee {
+                ecrire!(self.io, "📜 Quête '{}' : objectifs remplis, retournez voir votre commanditaire !", quest.name);
+            }
+            self.player.journal.set_status(quest_id, nouveau);
+        }
     }
-}
\ No newline at end of file
+
+    /// Affiche chaque objectif d'une quête avec son avancement
+    fn afficher_objectifs(&mut self, quest: &Quest) {
+        for objective in quest.all_objectives() {
+            let progress = self.player.objective_progress(quest.id, &objective);
+            let coche = if progress >= objective.target() { "✅" } else { "⬜" };
+            ecrire!(self.io, "     {} {} : {}/{}", coche, self.decrire_objectif(&objective), progress, objective.target());
+        }
+    }
+
+    /// Intitulé d'un objectif avec les noms tirés des données
+    fn decrire_objectif(&self, objective: &Objective) -> String {
+        match *objective {
+            Objective::Tuer { monster_id, .. } => {
+                let nom = self.all_monsters.iter().find(|m| m.id == monster_id).map(|m| m.name.as_str());
+                format!("Vaincre {}", nom.unwrap_or("un monstre inconnu"))
+            }
+            Objective::Atteindre { zone_id } => {
+                let nom = self.zones.get(&zone_id).map(|z| z.name.as_str());
+                format!("Se rendre à {}", nom.unwrap_or("une zone inconnue"))
+            }
+            Objective::Parler { npc_id } => {
+                let nom = self.all_npcs.iter().find(|n| n.id == npc_id).map(|n| n.name.as_str());
+                format!("Parler à {}", nom.unwrap_or("un inconnu"))
+            }
+            Objective::Livrer { item_id, .. } => {
+                let nom = self.all_items.iter().find(|i| i.id == item_id).map(|i| i.name.as_str());
+                format!("Apporter {}", nom.unwrap_or("un objet inconnu"))
+            }
+        }
+    }
+
+    /// Donne la récompense d'une quête et détaille ce qui a été reçu
+    fn accorder_recompense(&mut self, reward: &QuestReward) {
+        if reward.is_empty() {
+            ecrire!(self.io, "🙏 Aucune récompense, mais toute la gratitude du village !");
+            return;
+        }
+
+        ecrire!(self.io, "🎁 Récompense :");
+        if reward.gold > 0 {
+            ecrire!(self.io, "  🪙 {} pièces d'or", reward.gold);
+            self.player.add_gold(reward.gold);
+        }
+        for reward_item in &reward.items {
+            if let Some(item) = self.all_items.iter().find(|i| i.id == reward_item.id) {
+                ecrire!(self.io, "  📦 {} x{}", item.name, reward_item.quantity);
+                for _ in 0..reward_item.quantity {
+                    self.player.inventaire.push(item.clone());
+                }
+            }
+        }
+        if let Some(stats) = &reward.stats
+            && !stats.is_zero()
+        {
+            ecrire!(self.io, "  💪 Bonus permanent : {}", stats.describe());
+            self.player.base_stats.apply_delta(stats);
+        }
+        if reward.experience > 0 {
+            ecrire!(self.io, "  ✨ {} points d'expérience", reward.experience);
+            self.gagner_experience(reward.experience);
+        }
+    }
+}
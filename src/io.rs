@@ -0,0 +1,135 @@
+use crate::error::GameError;
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+use std::rc::Rc;
+
+/// Canal d'échange entre le moteur et le joueur
+pub trait GameIo {
+    /// Affiche une ligne de texte
+    fn emit(&mut self, message: &str);
+
+    /// Affiche `prompt` puis lit une ligne sans les espaces de bord.
+    /// Renvoie `None` quand l'entrée est fermée.
+    fn read_line(&mut self, prompt: &str) -> Option<String>;
+
+    /// Comme `read_line`, mais une entrée fermée devient `GameError::InputClosed`
+    fn prompt(&mut self, prompt: &str) -> Result<String, GameError> {
+        self.read_line(prompt).ok_or(GameError::InputClosed)
+    }
+}
+
+/// Équivalent de `println!` vers un `GameIo`
+#[macro_export]
+macro_rules! ecrire {
+    ($io:expr) => {
+        $io.emit("")
+    };
+    ($io:expr, $($arg:tt)*) => {
+        $io.emit(&format!($($arg)*))
+    };
+}
+
+/// Console : stdout pour l'affichage, stdin pour les saisies
+#[derive(Debug, Default)]
+pub struct TerminalIo;
+
+impl GameIo for TerminalIo {
+    fn emit(&mut self, message: &str) {
+        println!("{}", message);
+    }
+
+    fn read_line(&mut self, prompt: &str) -> Option<String> {
+        print!("{}", prompt);
+        io::stdout().flush().ok()?;
+        let mut input = String::new();
+        match io::stdin().lock().read_line(&mut input) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(input.trim().to_string()),
+        }
+    }
+}
+
+/// Saisies fournies à l'avance et affichage conservé en mémoire.
+/// Les clones partagent les mêmes tampons : on garde un clone pour relire la sortie.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptedIo {
+    inputs: Rc<RefCell<VecDeque<String>>>,
+    output: Rc<RefCell<Vec<String>>>,
+}
+
+impl ScriptedIo {
+    pub fn new<I, S>(inputs: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        ScriptedIo {
+            inputs: Rc::new(RefCell::new(inputs.into_iter().map(Into::into).collect())),
+            output: Rc::default(),
+        }
+    }
+
+    /// Ajoute des saisies à la fin du script
+    pub fn push_input(&self, input: impl Into<String>) {
+        self.inputs.borrow_mut().push_back(input.into());
+    }
+
+    /// Lignes affichées jusqu'ici (les invites comprises)
+    pub fn output(&self) -> Vec<String> {
+        self.output.borrow().clone()
+    }
+
+    /// Indique si une ligne affichée contient `text`
+    pub fn output_contains(&self, text: &str) -> bool {
+        self.output.borrow().iter().any(|line| line.contains(text))
+    }
+
+    /// Nombre de saisies pas encore consommées
+    pub fn remaining_inputs(&self) -> usize {
+        self.inputs.borrow().len()
+    }
+}
+
+impl GameIo for ScriptedIo {
+    fn emit(&mut self, message: &str) {
+        self.output.borrow_mut().push(message.to_string());
+    }
+
+    fn read_line(&mut self, prompt: &str) -> Option<String> {
+        self.output.borrow_mut().push(prompt.to_string());
+        self.inputs.borrow_mut().pop_front().map(|input| input.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saisies_dans_l_ordre() {
+        let mut io = ScriptedIo::new(["1", "  quit  "]);
+        assert_eq!(io.read_line("> ").as_deref(), Some("1"));
+        assert_eq!(io.read_line("> ").as_deref(), Some("quit"));
+        assert_eq!(io.read_line("> "), None);
+        assert!(matches!(io.prompt("> "), Err(GameError::InputClosed)));
+    }
+
+    #[test]
+    fn test_clones_partagent_la_sortie() {
+        let io = ScriptedIo::new(Vec::<String>::new());
+        let mut copie = io.clone();
+        ecrire!(copie, "Bonjour {} !", "Elara");
+        copie.push_input("2");
+
+        assert!(io.output_contains("Bonjour Elara !"));
+        assert_eq!(io.remaining_inputs(), 1);
+    }
+
+    #[test]
+    fn test_invites_enregistrees() {
+        let mut io = ScriptedIo::new(["o"]);
+        io.read_line("Charger ? ");
+        assert_eq!(io.output(), vec!["Charger ? ".to_string()]);
+    }
+}
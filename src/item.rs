@@ -1,3 +1,4 @@
+use crate::status::StatusApplication;
 use serde::{Deserialize, Serialize};
 
 /// Type d'objet dans le jeu
@@ -26,6 +27,9 @@ pub struct Item {
     pub strength: i32,
     pub defense: i32,
     pub agility: i32,
+    /// Altérations appliquées à qui consomme l'objet
+    #[serde(default)]
+    pub statuses: Vec<StatusApplication>,
 }
 
 impl Item {
@@ -64,6 +68,10 @@ impl Item {
             stats.push(format!("Agilité: {:+}", self.agility));
         }
 
+        for application in &self.statuses {
+            stats.push(application.effect.describe());
+        }
+
         if stats.is_empty() {
             "Aucun bonus".to_string()
         } else {
@@ -94,6 +102,7 @@ mod tests {
             strength,
             defense,
             agility,
+            statuses: Vec::new(),
         }
     }
 
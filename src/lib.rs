@@ -0,0 +1,62 @@
+//! Moteur du RPG en mode texte.
+//!
+//! Le binaire `rpg` n'est qu'une interface console (`TerminalIo`) au-dessus de cette bibliothèque :
+//! les outils (linter, éditeurs, bots) et les autres interfaces utilisent les mêmes
+//! types. Les plus courants sont réexportés à la racine :
+//!
+//! ```no_run
+//! use rpg::{load_game_data, Attributes, Game, GameConfig, Player, ScriptedIo, ValidationMode};
+//!
+//! let config = GameConfig::default();
+//! let (data, _issues) = load_game_data(&config.data_dir, ValidationMode::Strict)?;
+//! let player = Player::new("Bot".to_string(), Attributes::new(100, 10, 5, 5));
+//! let io = ScriptedIo::new(["1", "quit"]);
+//! let mut game = Game::from_parts(config, data, player, Box::new(io.clone()))?;
+//! game.run()?; // Première action du menu, puis quitter
+//! assert!(io.output_contains("Merci d'avoir joué"));
+//! # Ok::<(), rpg::GameError>(())
+//! ```
+
+pub mod ability;
+pub mod combat;
+pub mod item;
+pub mod quest;
+pub mod npc;
+pub mod dialogue;
+pub mod zone;
+pub mod player;
+pub mod game;
+pub mod data_loader;
+pub mod error;
+pub mod config;
+pub mod io;
+pub mod monster;
+pub mod rng;
+pub mod progression;
+pub mod lint;
+pub mod loot;
+pub mod shop;
+pub mod status;
+
+pub use ability::{Ability, AbilityEffect};
+pub use combat::{initiative_order, Action, CombatRounds, Combatant, Round};
+pub use config::GameConfig;
+pub use dialogue::{Condition, DialogueNode, DialogueResponse, DialogueTree, Effect};
+pub use data_loader::{load_game_data, load_raw_data, DataIssue, GameData, IssueKind, RawData, ValidationMode};
+pub use error::GameError;
+pub use game::Game;
+pub use io::{GameIo, ScriptedIo, TerminalIo};
+pub use item::{Item, ItemType};
+pub use loot::{roll_loot, LootDrop, LootEntry, LootGroup, QuantityRange, WeightedDrop};
+pub use monster::{
+    calculate_damage, calculate_hit_chance, check_dodge, resolve_monster_attack, resolve_player_attack,
+    AttackResult, GoldRange, Monster,
+};
+pub use npc::Npc;
+pub use player::{Attributes, Equipment, Player, SAVE_VERSION, START_ZONE_ID};
+pub use progression::{ClassProfile, LevelCurve};
+pub use quest::{Objective, Quest, QuestDialogue, QuestEntry, QuestJournal, QuestPrerequisites, QuestReward, QuestStatus, RewardItem};
+pub use rng::GameRng;
+pub use shop::{Shop, ShopState, StockEntry};
+pub use status::{StatusApplication, StatusEffect, StatusKind, StatusTick, Statuses};
+pub use zone::{DefeatedMonster, Direction, Encounter, SpawnId, Zone, ZoneState};
@@ -0,0 +1,393 @@
+use crate::data_loader::{DataIssue, IssueKind, RawData};
+use crate::dialogue::Effect;
+use crate::quest::Objective;
+use crate::zone::Direction;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Gravité d'un problème détecté par le linter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Contenu cassé : le linter échoue
+    Error,
+    /// Contenu suspect mais jouable
+    Warning,
+}
+
+/// Problème détecté dans les données, avec sa gravité et un code stable
+#[derive(Debug, Clone, Serialize)]
+pub struct LintIssue {
+    pub severity: Severity,
+    pub code: &'static str, // Ex: "duplicate-id", "one-way-exit"
+    #[serde(flatten)]
+    pub detail: DataIssue,
+}
+
+/// Résultat complet d'une analyse du dossier de données
+#[derive(Debug, Clone, Serialize)]
+pub struct LintReport {
+    pub errors: usize,
+    pub warnings: usize,
+    pub issues: Vec<LintIssue>,
+}
+
+impl LintReport {
+    fn new(issues: Vec<LintIssue>) -> Self {
+        let errors = issues.iter().filter(|i| i.severity == Severity::Error).count();
+        let warnings = issues.len() - errors;
+        LintReport { errors, warnings, issues }
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors > 0
+    }
+}
+
+/// Analyse les données brutes : références, doublons, zones inaccessibles,
+/// objets de quête impossibles à obtenir, loot vide et sorties à sens unique.
+pub fn lint(raw: &RawData, start_zone_id: u32) -> LintReport {
+    let mut issues: Vec<LintIssue> = raw
+        .validate()
+        .into_iter()
+        .map(|detail| {
+            let code = match (detail.kind, detail.file, detail.field) {
+                (IssueKind::MissingReference, _, _) => "dangling-reference",
+                (_, "levels.json", _) => "invalid-level-curve",
+                (_, "zones.json", "ambush") => "invalid-ambush",
+                (_, "zones.json", "encounters") => "invalid-encounter",
+                (_, "attributes.json", "abilities") => "invalid-ability",
+                (_, _, "gold") => "invalid-gold-range",
+                (_, _, "statuses" | "on_hit") => "invalid-status-effect",
+                (_, "monsters.json", "loot") => "invalid-loot-table",
+                _ => "invalid-value",
+            };
+            LintIssue { severity: Severity::Error, code, detail }
+        })
+        .collect();
+
+    check_duplicates(raw, &mut issues);
+    check_unreachable_zones(raw, start_zone_id, &mut issues);
+    check_one_way_exits(raw, &mut issues);
+    check_quest_items(raw, &mut issues);
+    check_empty_loot(raw, &mut issues);
+
+    LintReport::new(issues)
+}
+
+fn check_duplicates(raw: &RawData, issues: &mut Vec<LintIssue>) {
+    let mut report = |file, entity, ids: Vec<u32>| {
+        let mut seen = HashSet::new();
+        for id in ids {
+            if !seen.insert(id) {
+                issues.push(LintIssue {
+                    severity: Severity::Error,
+                    code: "duplicate-id",
+                    detail: DataIssue {
+                        file,
+                        entity,
+                        entity_id: id,
+                        field: "id",
+                        kind: IssueKind::InvalidValue,
+                        message: format!("l'ID {} est utilisé plusieurs fois", id),
+                    },
+                });
+            }
+        }
+    };
+
+    report("items.json", "objet", raw.items.iter().map(|i| i.id).collect());
+    report("quests.json", "quête", raw.quests.iter().map(|q| q.id).collect());
+    report("npcs.json", "PNJ", raw.npcs.iter().map(|n| n.id).collect());
+    report("monsters.json", "monstre", raw.monsters.iter().map(|m| m.id).collect());
+    report("zones.json", "zone", raw.zones.iter().map(|z| z.id).collect());
+}
+
+fn check_unreachable_zones(raw: &RawData, start_zone_id: u32, issues: &mut Vec<LintIssue>) {
+    let exits: HashMap<u32, Vec<u32>> = raw.zones
+        .iter()
+        .map(|zone| (zone.id, zone.connections.iter().map(|c| c.to).collect()))
+        .collect();
+
+    // Parcours en largeur depuis la zone de départ
+    let mut reachable = HashSet::from([start_zone_id]);
+    let mut queue = VecDeque::from([start_zone_id]);
+    while let Some(zone_id) = queue.pop_front() {
+        for next in exits.get(&zone_id).into_iter().flatten() {
+            if reachable.insert(*next) {
+                queue.push_back(*next);
+            }
+        }
+    }
+
+    for zone in &raw.zones {
+        if !reachable.contains(&zone.id) {
+            issues.push(LintIssue {
+                severity: Severity::Warning,
+                code: "unreachable-zone",
+                detail: DataIssue {
+                    file: "zones.json",
+                    entity: "zone",
+                    entity_id: zone.id,
+                    field: "connections",
+                    kind: IssueKind::Suspicious,
+                    message: format!("'{}' est inaccessible depuis la zone de départ {}", zone.name, start_zone_id),
+                },
+            });
+        }
+    }
+}
+
+fn check_one_way_exits(raw: &RawData, issues: &mut Vec<LintIssue>) {
+    for zone in &raw.zones {
+        for connection in &zone.connections {
+            let Some(target) = raw.zones.iter().find(|z| z.id == connection.to) else {
+                continue; // Déjà signalé comme référence cassée
+            };
+            if !target.connections.iter().any(|c| c.to == zone.id) {
+                let direction = Direction::parse(&connection.direction)
+                    .map(|d| format!("{:?}", d))
+                    .unwrap_or_else(|| connection.direction.clone());
+                issues.push(LintIssue {
+                    severity: Severity::Warning,
+                    code: "one-way-exit",
+                    detail: DataIssue {
+                        file: "zones.json",
+                        entity: "zone",
+                        entity_id: zone.id,
+                        field: "connections",
+                        kind: IssueKind::Suspicious,
+                        message: format!("la sortie {} vers la zone {} n'a pas de retour", direction, connection.to),
+                    },
+                });
+            }
+        }
+    }
+}
+
+fn check_quest_items(raw: &RawData, issues: &mut Vec<LintIssue>) {
+    // Un objet est obtenable s'il est lâché par un monstre présent dans une zone
+    // ou donné en récompense d'une quête ou au détour d'un dialogue, ou vendu par un marchand
+    let placed_monsters: HashSet<u32> = raw.zones
+        .iter()
+        .flat_map(|zone| zone.spawns().map(|spawn| spawn.id()))
+        .collect();
+    let obtainable: HashSet<u32> = raw.monsters
+        .iter()
+        .filter(|monster| placed_monsters.contains(&monster.id))
+        .flat_map(|monster| monster.loot.iter().flat_map(|entry| entry.item_ids()))
+        .chain(raw.quests.iter().flat_map(|quest| quest.reward.items.iter().map(|item| item.id)))
+        .chain(
+            raw.npcs
+                .iter()
+                .filter_map(|npc| npc.dialogue_tree.as_ref())
+                .flat_map(|tree| tree.responses().flat_map(|r| r.effects.iter()))
+                .filter_map(|effect| match effect {
+                    Effect::DonnerObjet { item_id, .. } => Some(*item_id),
+                    _ => None,
+                }),
+        )
+        .chain(
+            raw.npcs
+                .iter()
+                .filter_map(|npc| npc.shop.as_ref())
+                .flat_map(|shop| shop.stock.iter().filter(|entry| entry.quantity > 0).map(|entry| entry.item_id)),
+        )
+        .collect();
+
+    for quest in &raw.quests {
+        let livraisons = quest.objet_requis_id.map(|id| (id, "objet_requis_id")).into_iter().chain(
+            quest.objectives.iter().filter_map(|objective| match objective {
+                Objective::Livrer { item_id, .. } => Some((*item_id, "objectives")),
+                _ => None,
+            }),
+        );
+        for (item_id, field) in livraisons {
+            if raw.items.iter().any(|item| item.id == item_id) && !obtainable.contains(&item_id) {
+                issues.push(LintIssue {
+                    severity: Severity::Warning,
+                    code: "unobtainable-item",
+                    detail: DataIssue {
+                        file: "quests.json",
+                        entity: "quête",
+                        entity_id: quest.id,
+                        field,
+                        kind: IssueKind::Suspicious,
+                        message: format!("l'objet {} n'est ni lâché par un monstre présent dans une zone, ni donné par une quête ou un PNJ, ni vendu par un marchand", item_id),
+                    },
+                });
+            }
+        }
+    }
+}
+
+fn check_empty_loot(raw: &RawData, issues: &mut Vec<LintIssue>) {
+    for monster in &raw.monsters {
+        if monster.loot.is_empty() {
+            issues.push(LintIssue {
+                severity: Severity::Warning,
+                code: "empty-loot",
+                detail: DataIssue {
+                    file: "monsters.json",
+                    entity: "monstre",
+                    entity_id: monster.id,
+                    field: "loot",
+                    kind: IssueKind::Suspicious,
+                    message: format!("'{}' ne lâche aucun objet", monster.name),
+                },
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monster::MonsterRaw;
+    use crate::quest::Quest;
+    use crate::zone::{ConnectionRaw, ZoneRaw};
+
+    fn zone(id: u32, exits: &[(&str, u32)], monsters: Vec<u32>) -> ZoneRaw {
+        ZoneRaw {
+            id,
+            name: format!("Zone {}", id),
+            description: "".into(),
+            connections: exits
+                .iter()
+                .map(|(direction, to)| ConnectionRaw { direction: direction.to_string(), to: *to })
+                .collect(),
+            npcs: vec![],
+            monsters: Some(monsters.into_iter().map(Into::into).collect()),
+            respawn: 0,
+            encounters: vec![],
+            ambush: 0,
+        }
+    }
+
+    fn monstre(id: u32, loot: Vec<u32>) -> MonsterRaw {
+        MonsterRaw {
+            id,
+            name: format!("Monstre {}", id),
+            description: "".into(),
+            health: 10,
+            strength: 1,
+            defense: 0,
+            agility: 1,
+            loot: loot.into_iter().map(Into::into).collect(),
+            experience: 1,
+            gold: Default::default(),
+            on_hit: vec![],
+        }
+    }
+
+    fn donnees(zones: Vec<ZoneRaw>, monsters: Vec<MonsterRaw>) -> RawData {
+        let items = serde_json::from_str(r#"[
+            {"id": 1, "name": "Croc", "description": "", "value": 1, "item_type": "ObjetDeQuete",
+             "utilisable": false, "health": 0, "strength": 0, "defense": 0, "agility": 0}
+        ]"#).unwrap();
+        RawData {
+            classes: vec![],
+            levels: Default::default(),
+            items,
+            quests: vec![],
+            npcs: vec![],
+            monsters,
+            zones,
+        }
+    }
+
+    fn codes(report: &LintReport) -> Vec<&'static str> {
+        report.issues.iter().map(|i| i.code).collect()
+    }
+
+    #[test]
+    fn test_donnees_saines() {
+        let raw = donnees(
+            vec![zone(1, &[("North", 2)], vec![]), zone(2, &[("South", 1)], vec![1])],
+            vec![monstre(1, vec![1])],
+        );
+        let report = lint(&raw, 1);
+        assert!(report.issues.is_empty());
+        assert!(!report.has_errors());
+    }
+
+    #[test]
+    fn test_doublons_en_erreur() {
+        let raw = donnees(
+            vec![zone(1, &[], vec![1]), zone(1, &[], vec![])],
+            vec![monstre(1, vec![1])],
+        );
+        let report = lint(&raw, 1);
+        assert!(codes(&report).contains(&"duplicate-id"));
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn test_zone_inaccessible_et_sens_unique() {
+        let raw = donnees(
+            vec![zone(1, &[("North", 2)], vec![1]), zone(2, &[], vec![]), zone(3, &[("West", 1)], vec![])],
+            vec![monstre(1, vec![1])],
+        );
+        let report = lint(&raw, 1);
+        let codes = codes(&report);
+        assert_eq!(codes.iter().filter(|c| **c == "unreachable-zone").count(), 1);
+        assert_eq!(codes.iter().filter(|c| **c == "one-way-exit").count(), 2);
+        assert!(!report.has_errors());
+    }
+
+    #[test]
+    fn test_objet_de_quete_introuvable_et_loot_vide() {
+        let mut raw = donnees(vec![zone(1, &[], vec![2])], vec![monstre(1, vec![1]), monstre(2, vec![])]);
+        raw.quests.push(Quest::new(1, "Crocs".into(), "".into(), Some(1)));
+
+        let report = lint(&raw, 1);
+        let codes = codes(&report);
+        // Le monstre 1 lâche l'objet mais n'est placé dans aucune zone
+        assert!(codes.contains(&"unobtainable-item"));
+        assert!(codes.contains(&"empty-loot"));
+    }
+
+    #[test]
+    fn test_table_de_butin_invalide() {
+        let mut raw = donnees(vec![zone(1, &[], vec![1])], vec![monstre(1, vec![1])]);
+        raw.monsters[0].loot.push(serde_json::from_str(r#"{"item_id": 9, "chance": 120}"#).unwrap());
+
+        let report = lint(&raw, 1);
+        assert_eq!(codes(&report), vec!["invalid-loot-table", "dangling-reference"]);
+    }
+
+    #[test]
+    fn test_groupe_de_rencontre_invalide() {
+        let mut raw = donnees(vec![zone(1, &[], vec![1])], vec![monstre(1, vec![1])]);
+        raw.zones[0].encounters = serde_json::from_str(r#"[
+            {"name": "Vide", "monsters": []},
+            {"name": "Fantômes", "monsters": [7]}
+        ]"#).unwrap();
+
+        // Le code dépend de la nature du problème, pas du libellé du message
+        let report = lint(&raw, 1);
+        assert_eq!(codes(&report), vec!["invalid-encounter", "dangling-reference"]);
+    }
+
+    #[test]
+    fn test_alteration_invalide() {
+        let mut raw = donnees(vec![zone(1, &[], vec![1])], vec![monstre(1, vec![1])]);
+        raw.monsters[0].on_hit = serde_json::from_str(r#"[{"kind": "poison", "duration": 2}]"#).unwrap();
+
+        let report = lint(&raw, 1);
+        assert_eq!(codes(&report), vec!["invalid-status-effect"]);
+    }
+
+    #[test]
+    fn test_sortie_json() {
+        let raw = donnees(vec![zone(1, &[("North", 9)], vec![])], vec![]);
+        let report = lint(&raw, 1);
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["errors"], 1);
+        assert_eq!(json["issues"][0]["severity"], "error");
+        assert_eq!(json["issues"][0]["code"], "dangling-reference");
+        assert_eq!(json["issues"][0]["kind"], "missing-reference");
+        assert_eq!(json["issues"][0]["file"], "zones.json");
+        assert_eq!(json["issues"][0]["entity_id"], 1);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::rng::GameRng;
+use serde::{Deserialize, Serialize};
+
+/// Entrée de la table de butin d'un monstre (monsters.json)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LootEntry {
+    /// ID seul : l'objet tombe toujours, en un exemplaire
+    Objet(u32),
+    /// `{ "item_id": 1, "chance": 40, "quantity": { "min": 1, "max": 2 } }`
+    Tirage(LootDrop),
+    /// `{ "chance": 25, "one_of": [{ "item_id": 8, "weight": 1 }, ...] }` : un seul objet du groupe
+    Groupe(LootGroup),
+}
+
+/// Objet lâché avec une probabilité et une quantité variables
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LootDrop {
+    pub item_id: u32,
+    #[serde(default = "toujours")]
+    pub chance: u32, // En %, 100 par défaut
+    #[serde(default)]
+    pub quantity: QuantityRange,
+}
+
+/// Groupe dont un seul objet est tiré, proportionnellement aux poids
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LootGroup {
+    #[serde(default = "toujours")]
+    pub chance: u32, // Chance que le groupe donne quelque chose
+    pub one_of: Vec<WeightedDrop>,
+}
+
+/// Candidat d'un groupe de butin
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeightedDrop {
+    pub item_id: u32,
+    #[serde(default = "poids_unitaire")]
+    pub weight: u32,
+    #[serde(default)]
+    pub quantity: QuantityRange,
+}
+
+/// Nombre d'exemplaires lâchés (bornes incluses), 1 par défaut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuantityRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+fn toujours() -> u32 {
+    100
+}
+
+fn poids_unitaire() -> u32 {
+    1
+}
+
+impl Default for QuantityRange {
+    fn default() -> Self {
+        QuantityRange { min: 1, max: 1 }
+    }
+}
+
+impl QuantityRange {
+    pub fn roll(&self, rng: &mut GameRng) -> u32 {
+        rng.range(self.min, self.max)
+    }
+}
+
+impl From<u32> for LootEntry {
+    fn from(item_id: u32) -> Self {
+        LootEntry::Objet(item_id)
+    }
+}
+
+impl LootEntry {
+    /// Objets que cette entrée peut donner, pour vérifier les références
+    pub fn item_ids(&self) -> Vec<u32> {
+        match self {
+            LootEntry::Objet(item_id) => vec![*item_id],
+            LootEntry::Tirage(drop) => vec![drop.item_id],
+            LootEntry::Groupe(group) => group.one_of.iter().map(|drop| drop.item_id).collect(),
+        }
+    }
+
+    /// Incohérences de l'entrée (probabilité, quantités, poids)
+    pub fn problems(&self) -> Vec<String> {
+        let mut problemes = Vec::new();
+        let verifier_quantite = |item_id: u32, quantity: &QuantityRange, problemes: &mut Vec<String>| {
+            if quantity.min > quantity.max {
+                problemes.push(format!("quantité inversée pour l'objet {} ({} > {})", item_id, quantity.min, quantity.max));
+            }
+        };
+        let chance = match self {
+            LootEntry::Objet(_) => return problemes,
+            LootEntry::Tirage(drop) => {
+                verifier_quantite(drop.item_id, &drop.quantity, &mut problemes);
+                drop.chance
+            }
+            LootEntry::Groupe(group) => {
+                for drop in &group.one_of {
+                    verifier_quantite(drop.item_id, &drop.quantity, &mut problemes);
+                }
+                if group.one_of.iter().map(|drop| drop.weight).sum::<u32>() == 0 {
+                    problemes.push("groupe de butin vide ou sans poids".to_string());
+                }
+                group.chance
+            }
+        };
+        if chance == 0 || chance > 100 {
+            problemes.push(format!("probabilité {} hors de 1..=100", chance));
+        }
+        problemes
+    }
+
+    /// Tire le butin de l'entrée : `(item_id, quantité)` ou rien
+    pub fn roll(&self, rng: &mut GameRng) -> Option<(u32, u32)> {
+        let reussi = |chance: u32, rng: &mut GameRng| chance >= 100 || rng.chance(chance);
+        match self {
+            LootEntry::Objet(item_id) => Some((*item_id, 1)),
+            LootEntry::Tirage(drop) => {
+                reussi(drop.chance, rng).then(|| (drop.item_id, drop.quantity.roll(rng)))
+            }
+            LootEntry::Groupe(group) => {
+                if !reussi(group.chance, rng) {
+                    return None;
+                }
+                let total: u32 = group.one_of.iter().map(|drop| drop.weight).sum();
+                if total == 0 {
+                    return None;
+                }
+                let mut tirage = rng.range(0, total - 1);
+                let drop = group.one_of.iter().find(|drop| {
+                    if tirage < drop.weight {
+                        true
+                    } else {
+                        tirage -= drop.weight;
+                        false
+                    }
+                })?;
+                Some((drop.item_id, drop.quantity.roll(rng)))
+            }
+        }
+    }
+}
+
+/// Tire toute une table de butin, dans l'ordre des entrées
+pub fn roll_loot(table: &[LootEntry], rng: &mut GameRng) -> Vec<(u32, u32)> {
+    table
+        .iter()
+        .filter_map(|entry| entry.roll(rng))
+        .filter(|(_, quantity)| *quantity > 0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Vec<LootEntry> {
+        serde_json::from_str(r#"[
+            4,
+            {"item_id": 1, "chance": 50, "quantity": {"min": 1, "max": 3}},
+            {"chance": 100, "one_of": [{"item_id": 8, "weight": 1}, {"item_id": 2, "weight": 3}]}
+        ]"#).unwrap()
+    }
+
+    #[test]
+    fn test_table_depuis_json() {
+        let table = table();
+        assert_eq!(table[0], LootEntry::Objet(4));
+        assert_eq!(table[1].item_ids(), vec![1]);
+        assert_eq!(table[2].item_ids(), vec![8, 2]);
+        assert!(table.iter().all(|entry| entry.problems().is_empty()));
+    }
+
+    #[test]
+    fn test_tirages_selon_probabilites_et_poids() {
+        let table = table();
+        let mut rng = GameRng::new(5);
+        let (mut potions, mut tirages_potion, mut epees_magiques) = (0, 0, 0);
+        for _ in 0..1000 {
+            let butin = roll_loot(&table, &mut rng);
+            assert_eq!(butin[0], (4, 1)); // Toujours lâché
+            for (item_id, quantity) in &butin[1..] {
+                match item_id {
+                    1 => {
+                        assert!((1..=3).contains(quantity));
+                        potions += 1;
+                        tirages_potion += quantity;
+                    }
+                    8 => epees_magiques += 1,
+                    _ => assert_eq!(*item_id, 2),
+                }
+            }
+            assert_eq!(butin.iter().filter(|(id, _)| *id == 8 || *id == 2).count(), 1);
+        }
+        assert!((400..600).contains(&potions));
+        assert!(tirages_potion > potions);
+        assert!((150..350).contains(&epees_magiques));
+    }
+
+    #[test]
+    fn test_incoherences_signalees() {
+        let entries: Vec<LootEntry> = serde_json::from_str(r#"[
+            {"item_id": 1, "chance": 0},
+            {"item_id": 1, "quantity": {"min": 3, "max": 1}},
+            {"chance": 150, "one_of": []}
+        ]"#).unwrap();
+        assert_eq!(entries[0].problems(), vec!["probabilité 0 hors de 1..=100".to_string()]);
+        assert_eq!(entries[1].problems().len(), 1);
+        assert_eq!(entries[2].problems().len(), 2);
+    }
+}
@@ -1,21 +1,20 @@
-mod item;
-mod quest;
-mod npc;
-mod zone;
-mod player;
-mod game;
-mod data_loader;
-mod monster;
+use std::process::ExitCode;
+use rpg::{Game, GameConfig, TerminalIo};
 
-use std::error::Error;
-use game::Game;
-
-fn main() -> Result<(), Box<dyn Error>> {
+fn main() -> ExitCode {
     println!("🎮 Bienvenue dans le RPG !");
     println!("========================");
 
-    let mut game = Game::new()?;
-    game.run()?;
+    // Les emplacements viennent de RPG_DATA_DIR/RPG_SAVE_DIR/RPG_SAVE_FILE ou des options
+    // '--data-dir', '--save-dir', '--save-file' ; '--strict' refuse les données incohérentes
+    let result = GameConfig::from_env_and_args()
+        .and_then(|config| Game::new(config, Box::new(TerminalIo)))
+        .and_then(|mut game| game.run());
+
+    if let Err(e) = result {
+        eprintln!("❌ Erreur : {}", e);
+        return ExitCode::FAILURE;
+    }
 
-    Ok(())
-}
\ No newline at end of file
+    ExitCode::SUCCESS
+}
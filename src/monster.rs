@@ -1,4 +1,8 @@
 use serde::{Deserialize, Serialize};
+use crate::loot::LootEntry;
+use crate::player::Attributes;
+use crate::rng::GameRng;
+use crate::status::{StatusApplication, Statuses};
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct MonsterRaw {
@@ -9,8 +13,30 @@ pub struct MonsterRaw {
     pub strength: i32,
     pub defense: i32,
     pub agility: i32,
-    pub loot: Vec<u32>, // IDs des objets droppés
+    pub loot: Vec<LootEntry>, // Table de butin : IDs, tirages et groupes pondérés
     pub experience: u32, // XP donnée quand vaincu
+    #[serde(default)]
+    pub gold: GoldRange, // Pièces d'or lâchées, ex: { "min": 2, "max": 8 }
+    #[serde(default)]
+    pub on_hit: Vec<StatusApplication>, // Altérations infligées quand ses attaques touchent
+}
+
+/// Fourchette de pièces d'or (bornes incluses) lâchées par un monstre
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GoldRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl GoldRange {
+    pub fn new(min: u32, max: u32) -> Self {
+        GoldRange { min, max }
+    }
+
+    /// Tire le montant lâché avec le générateur de la partie
+    pub fn roll(&self, rng: &mut GameRng) -> u32 {
+        rng.range(self.min, self.max)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -23,8 +49,11 @@ pub struct Monster {
     pub strength: i32,
     pub defense: i32,
     pub agility: i32,
-    pub loot: Vec<u32>,
+    pub loot: Vec<LootEntry>,
     pub experience: u32,
+    pub gold: GoldRange,
+    pub on_hit: Vec<StatusApplication>,
+    pub statuses: Statuses, // Altérations subies pendant le combat en cours
 }
 
 impl Monster {
@@ -40,6 +69,9 @@ impl Monster {
             agility: raw.agility,
             loot: raw.loot,
             experience: raw.experience,
+            gold: raw.gold,
+            on_hit: raw.on_hit,
+            statuses: Statuses::default(),
         }
     }
 
@@ -51,6 +83,17 @@ impl Monster {
         self.current_health = (self.current_health - damage).max(0);
     }
 
+    /// Le monstre avec les bonus et malus de ses altérations, pour résoudre les attaques
+    pub fn with_status_modifiers(&self) -> Monster {
+        let modifiers = self.statuses.modifiers();
+        Monster {
+            strength: self.strength + modifiers.strength,
+            defense: self.defense + modifiers.defense,
+            agility: self.agility + modifiers.agility,
+            ..self.clone()
+        }
+    }
+
     pub fn health_percentage(&self) -> f32 {
         if self.max_health == 0 {
             0.0
@@ -71,7 +114,11 @@ impl Monster {
             "░".repeat(empty)
         );
 
-        format!("{} {}/{} HP", bar, self.current_health, self.max_health)
+        if self.statuses.is_empty() {
+            format!("{} {}/{} HP", bar, self.current_health, self.max_health)
+        } else {
+            format!("{} {}/{} HP {}", bar, self.current_health, self.max_health, self.statuses.describe())
+        }
     }
 }
 
@@ -93,34 +140,37 @@ pub fn calculate_damage(attacker_strength: i32, defender_defense: i32) -> i32 {
     (attacker_strength - defender_defense).max(1)
 }
 
-pub fn check_dodge(agility: i32) -> bool {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-    use std::time::{SystemTime, UNIX_EPOCH};
+/// Résout une attaque du joueur : elle peut rater, mais le monstre n'esquive pas
+pub fn resolve_player_attack(player_stats: &Attributes, monster: &Monster, rng: &mut GameRng) -> AttackResult {
+    if calculate_hit_chance(rng) {
+        AttackResult::Hit(calculate_damage(player_stats.strength, monster.defense))
+    } else {
+        AttackResult::Miss
+    }
+}
 
-    // Simple RNG basé sur le temps et l'agilité
-    let mut hasher = DefaultHasher::new();
-    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos().hash(&mut hasher);
-    agility.hash(&mut hasher);
-    let random = hasher.finish() % 100;
+/// Résout une attaque de monstre : elle peut rater ou être esquivée par le joueur
+pub fn resolve_monster_attack(monster: &Monster, player_stats: &Attributes, rng: &mut GameRng) -> AttackResult {
+    if !calculate_hit_chance(rng) {
+        AttackResult::Miss
+    } else if check_dodge(player_stats.agility, rng) {
+        AttackResult::Dodge
+    } else {
+        AttackResult::Hit(calculate_damage(monster.strength, player_stats.defense))
+    }
+}
 
+pub fn check_dodge(agility: i32, rng: &mut GameRng) -> bool {
     // Chance d'esquive : agility * 2% (max 30%)
-    let dodge_chance = (agility * 2).min(30) as u64;
-    random < dodge_chance
+    let dodge_chance = (agility * 2).clamp(0, 30) as u32;
+    rng.chance(dodge_chance)
 }
 
-pub fn calculate_hit_chance() -> bool {
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-    use std::time::{SystemTime, UNIX_EPOCH};
-
-    let mut hasher = DefaultHasher::new();
-    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos().hash(&mut hasher);
-    let random = hasher.finish() % 100;
-
+pub fn calculate_hit_chance(rng: &mut GameRng) -> bool {
     // 90% de chance de toucher
-    random < 90
+    rng.chance(90)
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,8 +185,11 @@ mod tests {
             strength: 15,
             defense: 8,
             agility: 5,
-            loot: vec![10, 11],
+            loot: vec![10.into(), 11.into()],
             experience: 45,
+            gold: GoldRange::new(5, 12),
+            on_hit: Vec::new(),
+            statuses: Statuses::default(),
         }
     }
 
@@ -150,8 +203,10 @@ mod tests {
             strength: 18,
             defense: 10,
             agility: 3,
-            loot: vec![15, 16, 17],
+            loot: vec![15.into(), 16.into(), 17.into()],
             experience: 60,
+            gold: GoldRange::new(10, 20),
+            on_hit: Vec::new(),
         };
 
         let monstre = Monster::from_raw(raw);
@@ -160,6 +215,18 @@ mod tests {
         assert_eq!(monstre.current_health, 120);
         assert_eq!(monstre.strength, 18);
         assert_eq!(monstre.loot.len(), 3);
+        assert_eq!(monstre.gold, GoldRange::new(10, 20));
+    }
+
+    #[test]
+    fn test_or_dans_la_fourchette() {
+        let monstre = creer_monstre_test();
+        let mut rng = GameRng::new(11);
+        for _ in 0..100 {
+            let or = monstre.gold.roll(&mut rng);
+            assert!((5..=12).contains(&or));
+        }
+        assert_eq!(GoldRange::default().roll(&mut rng), 0);
     }
 
     #[test]
@@ -197,6 +264,16 @@ mod tests {
         let barre_vide = monstre.health_bar();
         assert!(barre_vide.contains("░"));
         assert!(barre_vide.contains("0/80 HP"));
+
+        // Les altérations s'affichent à côté de la barre
+        monstre.statuses.apply(crate::status::StatusEffect {
+            kind: crate::status::StatusKind::Poison,
+            duration: 2,
+            potency: 3,
+            modifiers: Attributes::default(),
+            stacks: 1,
+        });
+        assert!(monstre.health_bar().ends_with("0/80 HP ☠️ Poison 3 (2 tours)"));
     }
 
     #[test]
@@ -208,10 +285,12 @@ mod tests {
 
     #[test]
     fn test_systeme_esquive() {
+        let mut rng = GameRng::new(12345);
+
         // Test avec agilité 0 - aucune esquive
         let mut esquives = 0;
         for _ in 0..50 {
-            if check_dodge(0) {
+            if check_dodge(0, &mut rng) {
                 esquives += 1;
             }
         }
@@ -220,7 +299,7 @@ mod tests {
         // Test avec agilité très élevée
         let mut esquives_elevees = 0;
         for _ in 0..200 {
-            if check_dodge(20) { // 20 * 2 = 40%, mais plafonné à 30%
+            if check_dodge(20, &mut rng) { // 20 * 2 = 40%, mais plafonné à 30%
                 esquives_elevees += 1;
             }
         }
@@ -230,13 +309,31 @@ mod tests {
 
     #[test]
     fn test_precision_attaque() {
+        let mut rng = GameRng::new(12345);
         let mut touches = 0;
         for _ in 0..100 {
-            if calculate_hit_chance() {
+            if calculate_hit_chance(&mut rng) {
                 touches += 1;
             }
         }
         // Environ 90% de touches attendues
         assert!(touches > 80 && touches <= 100);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_combat_rejouable_avec_la_meme_graine() {
+        let monstre = creer_monstre_test();
+        let stats = Attributes::new(100, 20, 10, 10);
+
+        let simuler = |seed| {
+            let mut rng = GameRng::new(seed);
+            (0..20)
+                .map(|_| format!("{:?}/{:?}",
+                                 resolve_player_attack(&stats, &monstre, &mut rng),
+                                 resolve_monster_attack(&monstre, &stats, &mut rng)))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(simuler(99), simuler(99));
+    }
+}
@@ -1,13 +1,21 @@
+use crate::dialogue::DialogueTree;
+use crate::player::Player;
 use crate::quest::Quest;
+use crate::shop::Shop;
 use serde::Deserialize;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 pub struct NpcRaw {
     pub id: u32,
     pub name: String,
     pub description: String,
+    #[serde(default)]
     pub dialogues: Vec<String>,
     pub quests: Vec<u32>, // IDs des quêtes
+    #[serde(default)]
+    pub dialogue_tree: Option<DialogueTree>, // Conversation à embranchements
+    #[serde(default)]
+    pub shop: Option<Shop>, // Marchandise des PNJ marchands
 }
 
 #[derive(Debug, Clone)]
@@ -17,6 +25,8 @@ pub struct Npc {
     pub description: String,
     pub dialogues: Vec<String>,
     pub quests: Vec<Quest>,
+    pub dialogue_tree: Option<DialogueTree>,
+    pub shop: Option<Shop>,
 }
 
 impl Npc {
@@ -44,6 +54,50 @@ impl Npc {
         }
     }
 
+    /// Quêtes que le PNJ propose au joueur : les siennes et les suites des quêtes
+    /// qu'il a données une fois rendues, sans celles déjà rendues ni celles dont
+    /// les prérequis ne sont pas remplis (sauf si elles ont déjà été acceptées)
+    pub fn offered_quests<'a>(&'a self, all_quests: &'a [Quest], player: &Player) -> Vec<&'a Quest> {
+        let mut candidates: Vec<&Quest> = self.quests.iter().collect();
+        let mut i = 0;
+        while i < candidates.len() {
+            let quest = candidates[i];
+            if player.journal.is_turned_in(quest.id) {
+                for follow_up in all_quests.iter().filter(|q| quest.follow_ups.contains(&q.id)) {
+                    if !candidates.iter().any(|c| c.id == follow_up.id) {
+                        candidates.push(follow_up);
+                    }
+                }
+            }
+            i += 1;
+        }
+
+        candidates
+            .into_iter()
+            .filter(|quest| !player.journal.is_turned_in(quest.id))
+            .filter(|quest| player.journal.status(quest.id).is_some() || player.meets_prerequisites(&quest.prerequisites))
+            .collect()
+    }
+
+    /// Indique si le PNJ donne cette quête, directement ou comme suite d'une des siennes
+    pub fn gives_quest(&self, quest_id: u32, all_quests: &[Quest]) -> bool {
+        let mut a_visiter: Vec<u32> = self.quests.iter().map(|q| q.id).collect();
+        let mut vues = Vec::new();
+        while let Some(id) = a_visiter.pop() {
+            if id == quest_id {
+                return true;
+            }
+            if vues.contains(&id) {
+                continue;
+            }
+            vues.push(id);
+            if let Some(quest) = all_quests.iter().find(|q| q.id == id) {
+                a_visiter.extend(&quest.follow_ups);
+            }
+        }
+        false
+    }
+
     pub fn from_raw(raw: NpcRaw, all_quests: &[Quest]) -> Self {
         let quests = raw
             .quests
@@ -57,6 +111,8 @@ impl Npc {
             description: raw.description,
             dialogues: raw.dialogues,
             quests,
+            dialogue_tree: raw.dialogue_tree,
+            shop: raw.shop,
         }
     }
 }
@@ -73,7 +129,12 @@ mod tests {
             name: "Retrouver l'Épée Perdue".to_string(),
             description: "Une épée légendaire a été perdue dans les profondeurs".to_string(),
             objet_requis_id: Some(42),
+            objectives: vec![],
             completed: false,
+            reward: Default::default(),
+            prerequisites: Default::default(),
+            follow_ups: vec![],
+            dialogue: Default::default(),
         }
     }
 
@@ -89,6 +150,8 @@ mod tests {
                 "Le village a besoin de votre aide.".to_string(),
             ],
             quests: vec![quete],
+            dialogue_tree: None,
+            shop: None,
         };
 
         assert_eq!(pnj.name(), "Sage du Village");
@@ -107,6 +170,8 @@ mod tests {
             description: "Un garde qui ne parle jamais".to_string(),
             dialogues: vec![],
             quests: vec![],
+            dialogue_tree: None,
+            shop: None,
         };
 
         let interaction = pnj.interact();
@@ -122,6 +187,8 @@ mod tests {
             description: "Un marchand itinérant".to_string(),
             dialogues: vec!["J'ai des objets rares à vendre !".to_string()],
             quests: vec![creer_quete_test()],
+            dialogue_tree: None,
+            shop: None,
         };
 
         let info_quete = pnj.quest_info();
@@ -137,6 +204,8 @@ mod tests {
             description: "Un simple villageois".to_string(),
             dialogues: vec!["Belle journée, n'est-ce pas ?".to_string()],
             quests: vec![],
+            dialogue_tree: None,
+            shop: None,
         };
 
         let info_quete = pnj.quest_info();
@@ -150,7 +219,12 @@ mod tests {
             name: "Quête 1".to_string(),
             description: "Première quête".to_string(),
             objet_requis_id: None,
+            objectives: vec![],
             completed: false,
+            reward: Default::default(),
+            prerequisites: Default::default(),
+            follow_ups: vec![],
+            dialogue: Default::default(),
         };
 
         let quete2 = Quest {
@@ -158,7 +232,12 @@ mod tests {
             name: "Quête 2".to_string(),
             description: "Deuxième quête".to_string(),
             objet_requis_id: Some(100),
+            objectives: vec![],
             completed: true,
+            reward: Default::default(),
+            prerequisites: Default::default(),
+            follow_ups: vec![],
+            dialogue: Default::default(),
         };
 
         let toutes_quetes = vec![quete1, quete2];
@@ -169,6 +248,8 @@ mod tests {
             description: "Distribue les missions".to_string(),
             dialogues: vec!["J'ai des tâches pour vous !".to_string()],
             quests: vec![10, 20, 999], // Le 999 n'existe pas
+            dialogue_tree: None,
+            shop: None,
         };
 
         let pnj = Npc::from_raw(pnj_raw, &toutes_quetes);
@@ -186,7 +267,12 @@ mod tests {
             name: "Test Quest".to_string(),
             description: "Desc".to_string(),
             objet_requis_id: None,
+            objectives: vec![],
             completed: false,
+            reward: Default::default(),
+            prerequisites: Default::default(),
+            follow_ups: vec![],
+            dialogue: Default::default(),
         };
         let npc = Npc {
             id: 1,
@@ -194,6 +280,8 @@ mod tests {
             description: "desc".to_string(),
             dialogues: vec!["Salut".to_string()],
             quests: vec![quest],
+            dialogue_tree: None,
+            shop: None,
         };
         assert!(npc.quest_info().contains("Test Quest"));
     }
@@ -206,7 +294,46 @@ mod tests {
             description: "Rien à dire".to_string(),
             dialogues: vec![],
             quests: vec![],
+            dialogue_tree: None,
+            shop: None,
         };
         assert!(npc.interact().contains("n'a rien à dire"));
     }
+
+    #[test]
+    fn test_quetes_proposees_selon_prerequis_et_suites() {
+        use crate::player::Attributes;
+        use crate::quest::QuestStatus;
+
+        let mut premiere = Quest::new(1, "Première".into(), "".into(), None);
+        premiere.follow_ups = vec![2];
+        let suite = Quest::new(2, "Suite".into(), "".into(), None);
+        let mut reservee = Quest::new(3, "Réservée".into(), "".into(), None);
+        reservee.prerequisites.min_level = 5;
+        let toutes = vec![premiere.clone(), suite, reservee.clone()];
+
+        let npc = Npc {
+            id: 1,
+            name: "Conteur".to_string(),
+            description: "".to_string(),
+            dialogues: vec![],
+            quests: vec![premiere.clone(), reservee],
+            dialogue_tree: None,
+            shop: None,
+        };
+        let mut player = Player::new("Testeur".into(), Attributes::new(10, 1, 1, 1));
+        let ids = |player: &Player| npc.offered_quests(&toutes, player).iter().map(|q| q.id).collect::<Vec<_>>();
+
+        assert_eq!(ids(&player), vec![1]);
+
+        player.accept_quest(&premiere);
+        player.journal.set_status(1, QuestStatus::Rendue);
+        assert_eq!(ids(&player), vec![2]);
+
+        player.level = 5;
+        assert_eq!(ids(&player), vec![3, 2]);
+
+        assert!(npc.gives_quest(2, &toutes));
+        assert!(!npc.gives_quest(9, &toutes));
+    }
 }
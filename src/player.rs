@@ -1,21 +1,38 @@
-use std::collections::HashMap;
-use std::io::{self, Write};
+use std::collections::{BTreeMap, HashMap};
 use serde::{Deserialize, Serialize};
 use crate::item::Item;
+use crate::quest::{Objective, Quest, QuestJournal, QuestPrerequisites};
+use crate::rng::GameRng;
+use crate::shop::ShopState;
+use crate::status::Statuses;
+use crate::zone::ZoneState;
+use crate::config::GameConfig;
+use crate::progression::{ClassProfile, LevelCurve};
+use crate::error::GameError;
+use crate::io::GameIo;
+use crate::ecrire;
 
 // Assuming we have an attributes module in the rpg crate
 // For now, I'll define a simple Attributes struct
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)] // Les stats absentes valent 0, ex: un bonus { "agility": 2 }
 pub struct Attributes {
     pub health: i32,
     pub strength: i32,
     pub defense: i32,
     pub agility: i32,
+    pub mana: i32, // Ressource des compétences de classe (mana ou énergie)
 }
 
 impl Attributes {
     pub fn new(health: i32, strength: i32, defense: i32, agility: i32) -> Self {
-        Attributes { health, strength, defense, agility }
+        Attributes { health, strength, defense, agility, mana: 0 }
+    }
+
+    /// Mêmes stats avec une réserve de mana
+    pub fn with_mana(mut self, mana: i32) -> Self {
+        self.mana = mana;
+        self
     }
 
     pub fn apply_delta(&mut self, delta: &Attributes) {
@@ -23,10 +40,31 @@ impl Attributes {
         self.strength += delta.strength;
         self.defense += delta.defense;
         self.agility += delta.agility;
+        self.mana += delta.mana;
+    }
+
+    /// Stats multipliées par `factor` (ex: gains de plusieurs niveaux)
+    pub fn scaled(&self, factor: i32) -> Attributes {
+        Attributes::new(self.health * factor, self.strength * factor, self.defense * factor, self.agility * factor)
+            .with_mana(self.mana * factor)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Attributes::default()
+    }
+
+    /// Liste lisible des stats non nulles, ex: "+2 force, +1 agilité"
+    pub fn describe(&self) -> String {
+        [(self.health, "santé"), (self.strength, "force"), (self.defense, "défense"), (self.agility, "agilité"), (self.mana, "mana")]
+            .iter()
+            .filter(|(value, _)| *value != 0)
+            .map(|(value, name)| format!("{:+} {}", value, name))
+            .collect::<Vec<_>>()
+            .join(", ")
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Equipment {
     pub arme: Option<Item>,
     pub armure: Option<Item>,
@@ -35,11 +73,7 @@ pub struct Equipment {
 
 impl Equipment {
     pub fn new() -> Self {
-        Equipment {
-            arme: None,
-            armure: None,
-            amulette: None,
-        }
+        Self::default()
     }
 
     pub fn get_total_stats(&self) -> Attributes {
@@ -68,26 +102,111 @@ impl Equipment {
     }
 }
 
+/// Zone où commence tout nouveau personnage
+pub const START_ZONE_ID: u32 = 1;
+
+/// Format des sauvegardes écrites par cette version ; 0 : sauvegarde sans version, parfois antérieure au mana
+pub const SAVE_VERSION: u32 = 1;
+
 /// Structure principale du personnage
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Player {
     pub nom: String,
     pub base_stats: Attributes, // Stats de base
     pub current_health: i32,    // Santé actuelle
+    #[serde(default)]
+    pub current_mana: i32,      // Mana actuel
     pub inventaire: Vec<Item>,  // Changé de Vec<String> à Vec<Item>
     pub equipment: Equipment,   // Équipement
     pub current_zone_id: u32,
     pub monster_kills: HashMap<u32, u32>, // monster_id -> nombre de kills
+    #[serde(default)]
+    pub rng: GameRng, // Générateur aléatoire de la partie, sauvegardé pour pouvoir rejouer
+    #[serde(default)]
+    pub classe: String, // Profil choisi dans attributes.json
+    #[serde(default)]
+    pub experience: u32, // XP totale accumulée
+    #[serde(default = "niveau_initial")]
+    pub level: u32,
+    #[serde(default)]
+    pub gold: u32, // Pièces d'or
+    #[serde(default)]
+    pub journal: QuestJournal, // Quêtes acceptées, terminées et rendues
+    #[serde(default)]
+    pub actions: u64, // Commandes jouées, sert d'horloge au monde
+    #[serde(default)]
+    pub shops: BTreeMap<u32, ShopState>, // npc_id -> stock restant des marchands
+    #[serde(default)]
+    pub zones: BTreeMap<u32, ZoneState>, // zone_id -> monstres vaincus en attente de réapparition
+    #[serde(default)]
+    pub statuses: Statuses, // Altérations en cours (poison, renforcement...)
+    #[serde(default)]
+    pub save_version: u32, // Voir SAVE_VERSION
 }
 
-/// Structure pour lire les profils depuis attributes.json
-#[derive(Debug, Deserialize)]
-struct AttributesProfile {
-    profile: String,
-    stats: Attributes,
+fn niveau_initial() -> u32 {
+    1
 }
 
 impl Player {
+    /// Crée un personnage sans équipement, en pleine santé, dans la zone de départ
+    pub fn new(nom: String, base_stats: Attributes) -> Self {
+        Player {
+            nom,
+            current_health: base_stats.health,
+            current_mana: base_stats.mana,
+            base_stats,
+            inventaire: Vec::new(),
+            equipment: Equipment::new(),
+            current_zone_id: START_ZONE_ID,
+            monster_kills: HashMap::new(),
+            rng: GameRng::default(),
+            classe: String::new(),
+            experience: 0,
+            level: niveau_initial(),
+            gold: 0,
+            journal: QuestJournal::new(),
+            actions: 0,
+            shops: BTreeMap::new(),
+            zones: BTreeMap::new(),
+            statuses: Statuses::default(),
+            save_version: SAVE_VERSION,
+        }
+    }
+
+    /// Ajoute des pièces d'or
+    pub fn add_gold(&mut self, amount: u32) {
+        self.gold = self.gold.saturating_add(amount);
+    }
+
+    /// Retire des pièces d'or si le joueur en a assez ; renvoie false sinon
+    pub fn spend_gold(&mut self, amount: u32) -> bool {
+        if self.gold < amount {
+            return false;
+        }
+        self.gold -= amount;
+        true
+    }
+
+    /// Ajoute de l'expérience et applique les gains de stats de chaque niveau franchi.
+    /// Un passage de niveau soigne complètement. Renvoie le nombre de niveaux gagnés.
+    pub fn gain_experience(&mut self, amount: u32, curve: &LevelCurve, growth: &Attributes) -> u32 {
+        self.experience = self.experience.saturating_add(amount);
+        let new_level = curve.level_for(self.experience);
+        if new_level <= self.level {
+            return 0;
+        }
+
+        let gained = new_level - self.level;
+        for _ in 0..gained {
+            self.base_stats.apply_delta(growth);
+        }
+        self.level = new_level;
+        self.current_health = self.get_max_health();
+        self.current_mana = self.get_max_mana();
+        gained
+    }
+
     /// Calcule les stats totales (base + équipement)
     pub fn get_total_stats(&self) -> Attributes {
         let mut total = self.base_stats.clone();
@@ -96,11 +215,36 @@ impl Player {
         total
     }
 
+    /// Stats utilisées en combat : stats totales plus bonus et malus des altérations
+    pub fn get_combat_stats(&self) -> Attributes {
+        let mut stats = self.get_total_stats();
+        stats.apply_delta(&self.statuses.modifiers());
+        stats
+    }
+
     /// Calcule la santé maximale
     pub fn get_max_health(&self) -> i32 {
         self.get_total_stats().health
     }
 
+    pub fn get_max_mana(&self) -> i32 {
+        self.get_total_stats().mana
+    }
+
+    /// Dépense du mana si le joueur en a assez ; renvoie false sinon
+    pub fn spend_mana(&mut self, cost: i32) -> bool {
+        if self.current_mana < cost {
+            return false;
+        }
+        self.current_mana -= cost;
+        true
+    }
+
+    /// Récupère du mana, sans dépasser le maximum
+    pub fn restore_mana(&mut self, amount: i32) {
+        self.current_mana = (self.current_mana + amount).min(self.get_max_mana()).max(0);
+    }
+
     /// Soigne le joueur
     pub fn heal(&mut self, amount: i32) {
         let max_health = self.get_max_health();
@@ -127,19 +271,87 @@ impl Player {
         *self.monster_kills.get(&monster_id).unwrap_or(&0)
     }
 
+    /// Nombre d'exemplaires d'un objet dans l'inventaire
+    pub fn count_items(&self, item_id: u32) -> u32 {
+        self.inventaire.iter().filter(|item| item.id == item_id).count() as u32
+    }
+
+    /// Retire jusqu'à `quantity` exemplaires d'un objet et les renvoie
+    pub fn remove_items(&mut self, item_id: u32, quantity: u32) -> Vec<Item> {
+        let mut removed = Vec::new();
+        while (removed.len() as u32) < quantity
+            && let Some(pos) = self.inventaire.iter().position(|item| item.id == item_id)
+        {
+            removed.push(self.inventaire.remove(pos));
+        }
+        removed
+    }
+
+    /// Accepte une quête en notant les victoires déjà acquises sur les monstres à vaincre
+    pub fn accept_quest(&mut self, quest: &Quest) -> bool {
+        let kills_at_start: BTreeMap<u32, u32> = quest
+            .all_objectives()
+            .iter()
+            .filter_map(|objective| match objective {
+                Objective::Tuer { monster_id, .. } => Some((*monster_id, self.get_monster_kills(*monster_id))),
+                _ => None,
+            })
+            .collect();
+        self.journal.accept(quest.id, kills_at_start)
+    }
+
+    /// Avancement d'un objectif, plafonné à sa cible (0 si la quête n'est pas acceptée)
+    pub fn objective_progress(&self, quest_id: u32, objective: &Objective) -> u32 {
+        let Some(entry) = self.journal.entry(quest_id) else {
+            return 0;
+        };
+        let progress = match objective {
+            Objective::Tuer { monster_id, .. } => {
+                let before = entry.kills_at_start.get(monster_id).copied().unwrap_or(0);
+                self.get_monster_kills(*monster_id).saturating_sub(before)
+            }
+            Objective::Atteindre { zone_id } => entry.zones_reached.contains(zone_id) as u32,
+            Objective::Parler { npc_id } => entry.npcs_talked.contains(npc_id) as u32,
+            Objective::Livrer { item_id, .. } => self.count_items(*item_id),
+        };
+        progress.min(objective.target())
+    }
+
+    /// Indique si tous les objectifs d'une quête acceptée sont remplis
+    pub fn quest_objectives_met(&self, quest: &Quest) -> bool {
+        self.journal.entry(quest.id).is_some()
+            && quest
+                .all_objectives()
+                .iter()
+                .all(|objective| self.objective_progress(quest.id, objective) >= objective.target())
+    }
+
+    /// Indique si le joueur remplit les conditions d'accès à une quête
+    pub fn meets_prerequisites(&self, prerequisites: &QuestPrerequisites) -> bool {
+        prerequisites.quests.iter().all(|id| self.journal.is_turned_in(*id))
+            && self.level >= prerequisites.min_level
+            && prerequisites.class.as_ref().is_none_or(|class| *class == self.classe)
+    }
+
     /// Crée un nouveau joueur via le processus de création de personnage
-    pub fn create_character() -> Result<Player, Box<dyn std::error::Error>> {
-        // Étape 1 : Charger les profils de classes
-        let profils = Self::charger_profils("data/attributes.json")?;
-        let noms_profils: Vec<&str> = profils.keys().map(|k| k.as_str()).collect();
+    pub fn create_character(config: &GameConfig, classes: &[ClassProfile], io: &mut dyn GameIo) -> Result<Player, GameError> {
+        // Étape 1 : Profils de classes lus dans attributes.json
+        let noms_profils: Vec<&str> = classes.iter().map(|c| c.profile.as_str()).collect();
+        if noms_profils.is_empty() {
+            return Err(GameError::UnknownEntity {
+                file: config.data_path("attributes.json"),
+                entity: "classe",
+                id: "aucune".to_string(),
+            });
+        }
 
         // Étape 2 : Nom du joueur
-        let nom = Self::lire_input("Entrez votre nom de personnage (laisser vide pour 'Inconnu') : ");
+        let nom = io.prompt("Entrez votre nom de personnage (laisser vide pour 'Inconnu') : ")?;
         let nom = if nom.is_empty() { "Inconnu".to_string() } else { nom };
 
         // Étape 3 : Choix du profil
-        let profil_choisi = Self::choisir_parmi("Choisissez une classe", &noms_profils);
-        let base_stats = profils.get(&profil_choisi).unwrap().clone();
+        let profil_choisi = Self::choisir_parmi(io, "Choisissez une classe", &noms_profils)?;
+        let base_stats = classes.iter().find(|c| c.profile == profil_choisi).unwrap().stats.clone();
 
         // Étape 4 : Choix de l'inventaire (objets de départ)
         let items_depart = vec![
@@ -154,6 +366,7 @@ impl Player {
                 strength: 0,
                 defense: 0,
                 agility: 0,
+                statuses: Vec::new(),
             },
             Item {
                 id: 2,
@@ -166,92 +379,65 @@ impl Player {
                 strength: 3,
                 defense: 0,
                 agility: 0,
+                statuses: Vec::new(),
             },
         ];
 
-        println!("Objets de départ :");
+        ecrire!(io, "Objets de départ :");
         for item in &items_depart {
-            println!("  - {} : {}", item.name, item.description);
+            ecrire!(io, "  - {} : {}", item.name, item.description);
         }
 
-        // Étape 5 : Zone de départ (toujours zone 1 pour commencer)
-        let current_zone_id = 1;
-        let current_health = base_stats.health;
-
-        // Étape 6 : Création du personnage
-        let player = Player {
-            nom,
-            base_stats,
-            current_health,
-            inventaire: items_depart,
-            equipment: Equipment::new(),
-            current_zone_id,
-            monster_kills: HashMap::new(),
-        };
+        // Étape 5 : Création du personnage dans la zone de départ
+        let mut player = Player::new(nom, base_stats);
+        player.classe = profil_choisi;
+        player.inventaire = items_depart;
 
-        println!("\n✅ Personnage créé avec succès :");
-        println!("Nom: {}", player.nom);
-        println!("Stats de base: {:?}", player.base_stats);
-        println!("Santé: {}/{}", player.current_health, player.get_max_health());
+        ecrire!(io, "\n✅ Personnage créé avec succès :");
+        ecrire!(io, "Nom: {}", player.nom);
+        ecrire!(io, "Stats de base: {:?}", player.base_stats);
+        ecrire!(io, "Santé: {}/{}", player.current_health, player.get_max_health());
 
         // Sauvegarde
-        let json = serde_json::to_string_pretty(&player)?;
-        std::fs::write("data/perso_save.json", json)?;
-        println!("💾 Sauvegarde effectuée dans data/perso_save.json !");
+        let save_path = config.save_path();
+        player.save_character(&save_path)?;
+        ecrire!(io, "💾 Sauvegarde effectuée dans {} !", save_path);
 
         Ok(player)
     }
 
     /// Charge un personnage depuis un fichier de sauvegarde
-    pub fn load_character(path: &str) -> Result<Player, Box<dyn std::error::Error>> {
-        let data = std::fs::read_to_string(path)?;
-        let player: Player = serde_json::from_str(&data)?;
-        Ok(player)
+    pub fn load_character(path: &str) -> Result<Player, GameError> {
+        let data = std::fs::read_to_string(path).map_err(|e| GameError::io(path, e))?;
+        serde_json::from_str(&data).map_err(|source| GameError::Parse { path: path.to_string(), source })
     }
 
     /// Sauvegarde le personnage
-    pub fn save_character(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let json = serde_json::to_string_pretty(self)?;
-        std::fs::write(path, json)?;
-        Ok(())
-    }
-
-    /// Fonction pour charger tous les profils depuis attributes.json
-    fn charger_profils(path: &str) -> Result<HashMap<String, Attributes>, Box<dyn std::error::Error>> {
-        let contenu = std::fs::read_to_string(path)?;
-        let profils: Vec<AttributesProfile> = serde_json::from_str(&contenu)?;
-
-        let mut map = HashMap::new();
-        for p in profils {
-            map.insert(p.profile, p.stats);
+    pub fn save_character(&self, path: &str) -> Result<(), GameError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|source| GameError::Serialize { path: path.to_string(), source })?;
+        // Le dossier de sauvegarde peut être distinct du dossier de données
+        if let Some(dir) = std::path::Path::new(path).parent() {
+            std::fs::create_dir_all(dir).map_err(|e| GameError::io(path, e))?;
         }
-        Ok(map)
-    }
-
-    /// Lit une ligne depuis la console (avec message)
-    fn lire_input(msg: &str) -> String {
-        print!("{}", msg);
-        io::stdout().flush().unwrap();
-        let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
-        input.trim().to_string()
+        std::fs::write(path, json).map_err(|e| GameError::io(path, e))
     }
 
     /// Menu pour sélectionner une valeur dans une liste
-    fn choisir_parmi(label: &str, options: &[&str]) -> String {
-        println!("{} :", label);
+    fn choisir_parmi(io: &mut dyn GameIo, label: &str, options: &[&str]) -> Result<String, GameError> {
+        ecrire!(io, "{} :", label);
         for (i, option) in options.iter().enumerate() {
-            println!("  [{}] {}", i + 1, option);
+            ecrire!(io, "  [{}] {}", i + 1, option);
         }
 
         loop {
-            let choix = Self::lire_input("Entrez le numéro de votre choix : ");
-            if let Ok(index) = choix.parse::<usize>() {
-                if index >= 1 && index <= options.len() {
-                    return options[index - 1].to_string();
-                }
+            let choix = io.prompt("Entrez le numéro de votre choix : ")?;
+            if let Ok(index) = choix.parse::<usize>()
+                && index >= 1 && index <= options.len()
+            {
+                return Ok(options[index - 1].to_string());
             }
-            println!("❌ Choix invalide, réessayez.");
+            ecrire!(io, "❌ Choix invalide, réessayez.");
         }
     }
 }
@@ -260,6 +446,7 @@ impl Player {
 mod tests {
     use super::*;
     use crate::item::{ItemType, Item};
+    use crate::status::{StatusEffect, StatusKind};
 
     fn dummy_item(name: &str, health: i32, strength: i32, defense: i32, agility: i32) -> Item {
         Item {
@@ -273,6 +460,7 @@ mod tests {
             strength,
             defense,
             agility,
+            statuses: Vec::new(),
         }
     }
 
@@ -282,10 +470,22 @@ mod tests {
             nom: "Testeur".to_string(),
             base_stats: base_stats.clone(),
             current_health: base_stats.health,
+            current_mana: base_stats.mana,
             inventaire: vec![],
             equipment: Equipment::new(),
             current_zone_id: 1,
             monster_kills: HashMap::new(),
+            rng: GameRng::new(0),
+            classe: "Guerrier".to_string(),
+            experience: 0,
+            level: 1,
+            gold: 0,
+            journal: QuestJournal::new(),
+            actions: 0,
+            shops: BTreeMap::new(),
+            zones: BTreeMap::new(),
+            statuses: Statuses::default(),
+            save_version: SAVE_VERSION,
         }
     }
 
@@ -337,5 +537,153 @@ mod tests {
         player.add_monster_kill(42);
         assert_eq!(player.get_monster_kills(42), 2);
     }
-}
 
+    #[test]
+    fn test_gain_de_niveau() {
+        let mut player = create_test_player();
+        let courbe = LevelCurve::new(vec![0, 50, 120, 220]);
+        let gain = Attributes::new(10, 2, 1, 0);
+        player.take_damage(40);
+
+        assert_eq!(player.gain_experience(30, &courbe, &gain), 0);
+        assert_eq!(player.current_health, 10);
+
+        // 30 + 100 = 130 XP : deux niveaux d'un coup
+        assert_eq!(player.gain_experience(100, &courbe, &gain), 2);
+        assert_eq!(player.level, 3);
+        assert_eq!(player.base_stats.health, 70);
+        assert_eq!(player.base_stats.strength, 14);
+        assert_eq!(player.current_health, player.get_max_health());
+    }
+
+    #[test]
+    fn test_ancienne_sauvegarde_sans_experience() {
+        let json = r#"{"nom": "Ancien", "base_stats": {"health": 50, "strength": 5, "defense": 5, "agility": 5},
+            "current_health": 50, "inventaire": [], "equipment": {"arme": null, "armure": null, "amulette": null},
+            "current_zone_id": 1, "monster_kills": {}}"#;
+        let player: Player = serde_json::from_str(json).unwrap();
+        assert_eq!(player.level, 1);
+        assert_eq!(player.experience, 0);
+        assert_eq!(player.gold, 0);
+        assert!(player.journal.is_empty());
+        assert_eq!(player.save_version, 0);
+    }
+
+    #[test]
+    fn test_description_des_stats() {
+        assert_eq!(Attributes::new(0, 2, 0, -1).describe(), "+2 force, -1 agilité");
+        assert!(Attributes::default().is_zero());
+        let partiel: Attributes = serde_json::from_str(r#"{"agility": 2}"#).unwrap();
+        assert_eq!(partiel, Attributes::new(0, 0, 0, 2));
+    }
+
+    #[test]
+    fn test_objectifs_comptes_apres_acceptation() {
+        let mut player = create_test_player();
+        player.add_monster_kill(1); // Avant la quête : ne compte pas
+
+        let mut quest = Quest::new(9, "Chasse".into(), "".into(), None);
+        quest.objectives = vec![
+            Objective::Tuer { monster_id: 1, count: 2 },
+            Objective::Atteindre { zone_id: 21 },
+            Objective::Livrer { item_id: 5, quantity: 2 },
+        ];
+        assert!(player.accept_quest(&quest));
+
+        player.add_monster_kill(1);
+        player.journal.record_zone(21);
+        player.inventaire.push(Item { id: 5, ..dummy_item("Herbes", 0, 0, 0, 0) });
+        assert_eq!(player.objective_progress(9, &quest.objectives[0]), 1);
+        assert_eq!(player.objective_progress(9, &quest.objectives[1]), 1);
+        assert_eq!(player.objective_progress(9, &quest.objectives[2]), 1);
+        assert!(!player.quest_objectives_met(&quest));
+
+        player.add_monster_kill(1);
+        player.add_monster_kill(1);
+        player.inventaire.push(Item { id: 5, ..dummy_item("Herbes", 0, 0, 0, 0) });
+        assert_eq!(player.objective_progress(9, &quest.objectives[0]), 2);
+        assert!(player.quest_objectives_met(&quest));
+
+        assert_eq!(player.remove_items(5, 2).len(), 2);
+        assert_eq!(player.count_items(5), 0);
+    }
+
+    #[test]
+    fn test_prerequis_de_quete() {
+        let mut player = create_test_player();
+        let prerequis = QuestPrerequisites { quests: vec![2], min_level: 2, class: Some("Guerrier".into()) };
+        assert!(player.meets_prerequisites(&QuestPrerequisites::default()));
+        assert!(!player.meets_prerequisites(&prerequis));
+
+        player.accept_quest(&Quest::new(2, "Clé".into(), "".into(), None));
+        player.journal.set_status(2, crate::quest::QuestStatus::Rendue);
+        player.level = 2;
+        assert!(player.meets_prerequisites(&prerequis));
+
+        player.classe = "Mage".into();
+        assert!(!player.meets_prerequisites(&prerequis));
+    }
+
+    #[test]
+    fn test_bourse() {
+        let mut player = create_test_player();
+        player.add_gold(30);
+        assert!(!player.spend_gold(50));
+        assert_eq!(player.gold, 30);
+        assert!(player.spend_gold(25));
+        assert_eq!(player.gold, 5);
+    }
+
+    #[test]
+    fn test_reserve_de_mana() {
+        let mut player = create_test_player();
+        player.base_stats.mana = 30;
+        player.current_mana = 12;
+        assert!(!player.spend_mana(15));
+        assert!(player.spend_mana(10));
+        assert_eq!(player.current_mana, 2);
+        player.restore_mana(50);
+        assert_eq!(player.current_mana, 30);
+
+        // Un passage de niveau recharge aussi le mana
+        player.current_mana = 0;
+        let courbe = LevelCurve::new(vec![0, 50]);
+        player.gain_experience(50, &courbe, &Attributes::new(0, 0, 0, 0).with_mana(5));
+        assert_eq!(player.current_mana, 35);
+        assert_eq!(Attributes::default().with_mana(5).describe(), "+5 mana");
+    }
+
+    #[test]
+    fn test_sauvegarde_et_chargement() {
+        let path = std::env::temp_dir().join(format!("rpg_save_test_{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let mut player = create_test_player();
+        player.add_monster_kill(3);
+        player.add_gold(42);
+        player.statuses.apply(StatusEffect {
+            kind: StatusKind::Renforcement,
+            duration: 4,
+            potency: 0,
+            modifiers: Attributes::new(0, 3, 0, 0),
+            stacks: 1,
+        });
+        player.accept_quest(&Quest::new(2, "Clé".into(), "".into(), Some(4)));
+        player.save_character(path).expect("Sauvegarde");
+
+        let loaded = Player::load_character(path).expect("Chargement");
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(loaded.nom, "Testeur");
+        assert_eq!(loaded.get_monster_kills(3), 1);
+        assert_eq!(loaded.gold, 42);
+        assert_eq!(loaded.journal, player.journal);
+        assert_eq!(loaded.statuses, player.statuses);
+        assert_eq!(loaded.get_combat_stats().strength, player.get_total_stats().strength + 3);
+    }
+
+    #[test]
+    fn test_chargement_sauvegarde_absente() {
+        let err = Player::load_character("data/aucune_sauvegarde.json").unwrap_err();
+        assert!(matches!(err, GameError::MissingFile { .. }));
+    }
+}
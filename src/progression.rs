@@ -0,0 +1,89 @@
+use crate::ability::Ability;
+use crate::player::Attributes;
+use serde::{Deserialize, Serialize};
+
+/// Classe jouable telle que décrite dans attributes.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassProfile {
+    pub profile: String,
+    pub stats: Attributes,
+    /// Stats gagnées à chaque passage de niveau
+    #[serde(default)]
+    pub level_up: Attributes,
+    /// Compétences utilisables en combat, débloquées selon le niveau
+    #[serde(default)]
+    pub abilities: Vec<Ability>,
+}
+
+impl ClassProfile {
+    /// Compétences disponibles à ce niveau, dans l'ordre des données
+    pub fn abilities_at(&self, level: u32) -> Vec<&Ability> {
+        self.abilities.iter().filter(|ability| ability.is_unlocked(level)).collect()
+    }
+}
+
+/// Courbe d'expérience lue depuis levels.json : l'élément `i` est l'XP totale
+/// nécessaire pour atteindre le niveau `i + 1` (le premier vaut normalement 0).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct LevelCurve {
+    pub thresholds: Vec<u32>,
+}
+
+impl LevelCurve {
+    pub fn new(thresholds: Vec<u32>) -> Self {
+        LevelCurve { thresholds }
+    }
+
+    /// Niveau correspondant à une quantité totale d'expérience (au moins 1)
+    pub fn level_for(&self, experience: u32) -> u32 {
+        let reached = self.thresholds.iter().filter(|t| **t <= experience).count() as u32;
+        reached.max(1)
+    }
+
+    /// XP totale requise pour le niveau suivant, `None` au niveau maximum
+    pub fn next_threshold(&self, level: u32) -> Option<u32> {
+        self.thresholds.get(level as usize).copied()
+    }
+
+    pub fn max_level(&self) -> u32 {
+        (self.thresholds.len() as u32).max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn courbe() -> LevelCurve {
+        LevelCurve::new(vec![0, 50, 120, 220])
+    }
+
+    #[test]
+    fn test_niveau_selon_experience() {
+        let courbe = courbe();
+        assert_eq!(courbe.level_for(0), 1);
+        assert_eq!(courbe.level_for(49), 1);
+        assert_eq!(courbe.level_for(50), 2);
+        assert_eq!(courbe.level_for(219), 3);
+        assert_eq!(courbe.level_for(10_000), 4);
+        assert_eq!(LevelCurve::default().level_for(500), 1);
+    }
+
+    #[test]
+    fn test_seuil_suivant() {
+        let courbe = courbe();
+        assert_eq!(courbe.next_threshold(1), Some(50));
+        assert_eq!(courbe.next_threshold(3), Some(220));
+        assert_eq!(courbe.next_threshold(4), None);
+        assert_eq!(courbe.max_level(), 4);
+    }
+
+    #[test]
+    fn test_profil_sans_gain_de_niveau() {
+        let json = r#"{"profile": "Mage", "stats": {"health": 80, "strength": 6, "defense": 5, "agility": 10}}"#;
+        let profil: ClassProfile = serde_json::from_str(json).unwrap();
+        assert_eq!(profil.level_up.health, 0);
+        assert_eq!(profil.stats.agility, 10);
+    }
+}
@@ -1,4 +1,6 @@
+use crate::player::Attributes;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Représente une quête du jeu
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -10,9 +12,106 @@ pub struct Quest {
     /// Description détaillée
     pub description: String,
     /// Si la quête requiert un objet spécifique (par son ID)
+    #[serde(default)]
     pub objet_requis_id: Option<u32>,
-    /// Statut d'achèvement de la quête
+    /// Objectifs à remplir, en plus de l'éventuel objet requis
+    #[serde(default)]
+    pub objectives: Vec<Objective>,
+    /// Statut d'achèvement de la quête dans les données ; l'avancement du joueur
+    /// est suivi par son `QuestJournal`
     pub completed: bool,
+    /// Ce que reçoit le joueur en rendant la quête
+    #[serde(default)]
+    pub reward: QuestReward,
+    /// Conditions pour que la quête soit proposée
+    #[serde(default)]
+    pub prerequisites: QuestPrerequisites,
+    /// Quêtes que le même PNJ propose une fois celle-ci rendue
+    #[serde(default)]
+    pub follow_ups: Vec<u32>,
+    /// Répliques du PNJ selon l'étape de la quête
+    #[serde(default)]
+    pub dialogue: QuestDialogue,
+}
+
+/// Répliques optionnelles du commanditaire ; des phrases génériques les remplacent
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct QuestDialogue {
+    /// Quand il propose la quête (sinon la description)
+    pub offer: Option<String>,
+    /// Quand la quête est acceptée mais pas terminée
+    pub active: Option<String>,
+    /// Quand la quête est rendue
+    pub done: Option<String>,
+}
+
+/// Conditions d'accès à une quête, toutes optionnelles
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct QuestPrerequisites {
+    /// Quêtes à avoir rendues
+    pub quests: Vec<u32>,
+    /// Niveau minimum du joueur (0 : aucun)
+    pub min_level: u32,
+    /// Classe requise, telle que nommée dans attributes.json
+    pub class: Option<String>,
+}
+
+/// Objectif d'une quête, ex: `{ "type": "tuer", "monster_id": 1, "count": 3 }`
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum Objective {
+    /// Vaincre `count` monstres `monster_id` après avoir accepté la quête
+    Tuer { monster_id: u32, count: u32 },
+    /// Entrer dans la zone `zone_id`
+    Atteindre { zone_id: u32 },
+    /// Parler au PNJ `npc_id`
+    Parler { npc_id: u32 },
+    /// Remettre `quantity` objets `item_id` au commanditaire
+    Livrer { item_id: u32, quantity: u32 },
+}
+
+impl Objective {
+    /// Valeur à atteindre pour remplir l'objectif
+    pub fn target(&self) -> u32 {
+        match self {
+            Objective::Tuer { count, .. } => *count,
+            Objective::Livrer { quantity, .. } => *quantity,
+            Objective::Atteindre { .. } | Objective::Parler { .. } => 1,
+        }
+    }
+}
+
+/// Récompense déclarée dans quests.json
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct QuestReward {
+    pub gold: u32,
+    pub experience: u32,
+    /// Objets donnés, avec leur quantité
+    pub items: Vec<RewardItem>,
+    /// Bonus permanent ajouté aux stats de base
+    pub stats: Option<Attributes>,
+}
+
+impl QuestReward {
+    pub fn is_empty(&self) -> bool {
+        self.gold == 0 && self.experience == 0 && self.items.is_empty()
+            && self.stats.as_ref().is_none_or(|stats| stats.is_zero())
+    }
+}
+
+/// Objet d'une récompense : `{ "id": 1, "quantity": 2 }`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct RewardItem {
+    pub id: u32,
+    #[serde(default = "quantite_unitaire")]
+    pub quantity: u32,
+}
+
+fn quantite_unitaire() -> u32 {
+    1
 }
 
 impl Quest {
@@ -24,7 +123,18 @@ impl Quest {
         description: String,
         objet_requis_id: Option<u32>,
     ) -> Self {
-        Quest { id, name, description, objet_requis_id, completed: false }
+        Quest {
+            id,
+            name,
+            description,
+            objet_requis_id,
+            objectives: Vec::new(),
+            completed: false,
+            reward: QuestReward::default(),
+            prerequisites: QuestPrerequisites::default(),
+            follow_ups: Vec::new(),
+            dialogue: QuestDialogue::default(),
+        }
     }
 
     /// Marque la quête comme complétée
@@ -51,12 +161,143 @@ impl Quest {
     pub fn required_object_id(&self) -> Option<u32> {
         self.objet_requis_id
     }
+
+    /// Tous les objectifs, l'objet requis devenant une livraison d'un exemplaire
+    pub fn all_objectives(&self) -> Vec<Objective> {
+        self.objet_requis_id
+            .map(|item_id| Objective::Livrer { item_id, quantity: 1 })
+            .into_iter()
+            .chain(self.objectives.iter().copied())
+            .collect()
+    }
+}
+
+/// Étape d'une quête dans le journal du joueur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuestStatus {
+    /// Acceptée, aucun objectif rempli
+    Acceptee,
+    /// Objectifs partiellement remplis
+    EnCours,
+    /// Objectifs remplis, à rendre au PNJ
+    Terminee,
+    /// Rendue au PNJ, récompense reçue
+    Rendue,
+}
+
+impl QuestStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            QuestStatus::Acceptee => "acceptée",
+            QuestStatus::EnCours => "en cours",
+            QuestStatus::Terminee => "terminée",
+            QuestStatus::Rendue => "rendue",
+        }
+    }
+}
+
+/// Suivi d'une quête acceptée
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuestEntry {
+    pub status: QuestStatus,
+    /// Victoires par monstre au moment de l'acceptation
+    pub kills_at_start: BTreeMap<u32, u32>,
+    /// Zones visitées depuis l'acceptation
+    pub zones_reached: BTreeSet<u32>,
+    /// PNJ rencontrés depuis l'acceptation
+    pub npcs_talked: BTreeSet<u32>,
+}
+
+impl QuestEntry {
+    fn new(kills_at_start: BTreeMap<u32, u32>) -> Self {
+        QuestEntry {
+            status: QuestStatus::Acceptee,
+            kills_at_start,
+            zones_reached: BTreeSet::new(),
+            npcs_talked: BTreeSet::new(),
+        }
+    }
+
+    /// Quête acceptée mais pas encore rendue
+    pub fn is_active(&self) -> bool {
+        self.status != QuestStatus::Rendue
+    }
+}
+
+/// Journal des quêtes du joueur, sauvegardé avec le personnage
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct QuestJournal {
+    entries: BTreeMap<u32, QuestEntry>, // quest_id -> suivi
+}
+
+impl QuestJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Étape de la quête, `None` si elle n'a jamais été acceptée
+    pub fn status(&self, quest_id: u32) -> Option<QuestStatus> {
+        self.entries.get(&quest_id).map(|entry| entry.status)
+    }
+
+    pub fn entry(&self, quest_id: u32) -> Option<&QuestEntry> {
+        self.entries.get(&quest_id)
+    }
+
+    /// Ajoute la quête au journal avec le compte de victoires actuel ;
+    /// renvoie false si elle y était déjà
+    pub fn accept(&mut self, quest_id: u32, kills_at_start: BTreeMap<u32, u32>) -> bool {
+        if self.entries.contains_key(&quest_id) {
+            return false;
+        }
+        self.entries.insert(quest_id, QuestEntry::new(kills_at_start));
+        true
+    }
+
+    /// Change l'étape d'une quête déjà acceptée
+    pub fn set_status(&mut self, quest_id: u32, status: QuestStatus) {
+        if let Some(entry) = self.entries.get_mut(&quest_id) {
+            entry.status = status;
+        }
+    }
+
+    /// Retire une quête du journal (abandon) ; elle pourra être proposée à nouveau
+    pub fn remove(&mut self, quest_id: u32) -> Option<QuestEntry> {
+        self.entries.remove(&quest_id)
+    }
+
+    pub fn is_turned_in(&self, quest_id: u32) -> bool {
+        self.status(quest_id) == Some(QuestStatus::Rendue)
+    }
+
+    /// Note l'entrée dans une zone pour les quêtes en cours
+    pub fn record_zone(&mut self, zone_id: u32) {
+        for entry in self.entries.values_mut().filter(|e| e.is_active()) {
+            entry.zones_reached.insert(zone_id);
+        }
+    }
+
+    /// Note une conversation avec un PNJ pour les quêtes en cours
+    pub fn record_npc(&mut self, npc_id: u32) {
+        for entry in self.entries.values_mut().filter(|e| e.is_active()) {
+            entry.npcs_talked.insert(npc_id);
+        }
+    }
+
+    /// Quêtes du journal par ID croissant
+    pub fn entries(&self) -> impl Iterator<Item = (u32, QuestStatus)> + '_ {
+        self.entries.iter().map(|(id, entry)| (*id, entry.status))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use serde_json;
 
     #[test]
     fn test_new_without_object() {
@@ -96,4 +337,68 @@ mod tests {
         let deserialized: Quest = serde_json::from_str(&json).expect("Deserialization failed");
         assert_eq!(quest, deserialized);
     }
+
+    #[test]
+    fn test_recompense_depuis_json() {
+        let json = r#"{"id": 5, "name": "Loups", "description": "", "objet_requis_id": null, "completed": false,
+            "reward": {"gold": 40, "items": [{"id": 1, "quantity": 3}, {"id": 7}], "stats": {"strength": 1}}}"#;
+        let quest: Quest = serde_json::from_str(json).unwrap();
+        assert_eq!(quest.reward.gold, 40);
+        assert_eq!(quest.reward.experience, 0);
+        assert_eq!(quest.reward.items[0], RewardItem { id: 1, quantity: 3 });
+        assert_eq!(quest.reward.items[1].quantity, 1);
+        assert_eq!(quest.reward.stats, Some(Attributes::new(0, 1, 0, 0)));
+        assert!(!quest.reward.is_empty());
+        assert!(Quest::new(6, "Vide".into(), "".into(), None).reward.is_empty());
+    }
+
+    #[test]
+    fn test_prerequis_et_suites_depuis_json() {
+        let json = r#"{"id": 5, "name": "Le Coffre", "description": "", "completed": false,
+            "prerequisites": {"quests": [2], "class": "Paladin"}, "follow_ups": [6]}"#;
+        let quest: Quest = serde_json::from_str(json).unwrap();
+        assert_eq!(quest.prerequisites.quests, vec![2]);
+        assert_eq!(quest.prerequisites.min_level, 0);
+        assert_eq!(quest.prerequisites.class.as_deref(), Some("Paladin"));
+        assert_eq!(quest.follow_ups, vec![6]);
+    }
+
+    #[test]
+    fn test_journal_de_quetes() {
+        let mut journal = QuestJournal::new();
+        assert_eq!(journal.status(1), None);
+        assert!(journal.accept(1, BTreeMap::from([(4, 2)])));
+        assert!(!journal.accept(1, BTreeMap::new()));
+        assert_eq!(journal.status(1), Some(QuestStatus::Acceptee));
+        journal.record_zone(12);
+
+        journal.set_status(1, QuestStatus::Rendue);
+        assert!(journal.is_turned_in(1));
+        journal.record_npc(3); // Quête rendue : plus de suivi
+
+        let entry = journal.entry(1).unwrap();
+        assert_eq!(entry.kills_at_start.get(&4), Some(&2));
+        assert!(entry.zones_reached.contains(&12));
+        assert!(entry.npcs_talked.is_empty());
+
+        let json = serde_json::to_string(&journal).unwrap();
+        let restaure: QuestJournal = serde_json::from_str(&json).unwrap();
+        assert_eq!(restaure, journal);
+
+        assert!(journal.remove(1).is_some());
+        assert_eq!(journal.status(1), None);
+    }
+
+    #[test]
+    fn test_objectifs_depuis_json() {
+        let json = r#"{"id": 4, "name": "Menace", "description": "", "completed": false, "objet_requis_id": 5,
+            "objectives": [{"type": "tuer", "monster_id": 5, "count": 2}, {"type": "parler", "npc_id": 2}]}"#;
+        let quest: Quest = serde_json::from_str(json).unwrap();
+        assert_eq!(quest.all_objectives(), vec![
+            Objective::Livrer { item_id: 5, quantity: 1 },
+            Objective::Tuer { monster_id: 5, count: 2 },
+            Objective::Parler { npc_id: 2 },
+        ]);
+        assert_eq!(quest.all_objectives()[1].target(), 2);
+    }
 }
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Générateur pseudo-aléatoire de la partie (SplitMix64).
+/// Deux générateurs créés avec la même graine produisent exactement les mêmes tirages,
+/// ce qui permet de rejouer une partie à partir de sa graine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameRng {
+    seed: u64,
+    state: u64,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        GameRng { seed, state: seed }
+    }
+
+    /// Graine tirée de l'horloge, pour une partie sans graine imposée
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        Self::new(nanos)
+    }
+
+    /// Graine initiale, à joindre aux rapports de bug
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Entier dans l'intervalle `[min, max]` (bornes incluses)
+    pub fn range(&mut self, min: u32, max: u32) -> u32 {
+        if max <= min {
+            return min;
+        }
+        let span = (max - min) as u64 + 1;
+        min + (self.next_u64() % span) as u32
+    }
+
+    /// Vrai avec une probabilité de `percent` %
+    pub fn chance(&mut self, percent: u32) -> bool {
+        self.range(0, 99) < percent
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_time()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_meme_graine_memes_tirages() {
+        let mut a = GameRng::new(42);
+        let mut b = GameRng::new(42);
+        let tirages_a: Vec<u64> = (0..10).map(|_| a.next_u64()).collect();
+        let tirages_b: Vec<u64> = (0..10).map(|_| b.next_u64()).collect();
+        assert_eq!(tirages_a, tirages_b);
+        assert_ne!(GameRng::new(43).next_u64(), tirages_a[0]);
+    }
+
+    #[test]
+    fn test_intervalle_respecte() {
+        let mut rng = GameRng::new(7);
+        for _ in 0..1000 {
+            let n = rng.range(3, 6);
+            assert!((3..=6).contains(&n));
+        }
+        assert_eq!(rng.range(5, 5), 5);
+        assert_eq!(rng.range(9, 2), 9);
+    }
+
+    #[test]
+    fn test_chances_extremes() {
+        let mut rng = GameRng::new(1);
+        assert!((0..100).all(|_| !rng.chance(0)));
+        assert!((0..100).all(|_| rng.chance(100)));
+    }
+
+    #[test]
+    fn test_etat_sauvegarde_reprend_la_sequence() {
+        let mut rng = GameRng::new(2024);
+        rng.next_u64();
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restaure: GameRng = serde_json::from_str(&json).unwrap();
+        assert_eq!(restaure.seed(), 2024);
+        assert_eq!(restaure.next_u64(), rng.next_u64());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::item::{Item, ItemType};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Boutique d'un PNJ marchand, déclarée dans npcs.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shop {
+    pub stock: Vec<StockEntry>,
+    /// Part de `Item.value` payée au joueur quand il vend un objet
+    #[serde(default = "reprise_par_defaut")]
+    pub sell_multiplier: f32,
+    /// Nombre d'actions du joueur avant réapprovisionnement (0 : jamais)
+    #[serde(default)]
+    pub restock_every: u64,
+}
+
+/// Article en vente : `{ "item_id": 1, "quantity": 5, "price_multiplier": 1.2 }`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StockEntry {
+    pub item_id: u32,
+    pub quantity: u32,
+    /// Prix = `Item.value` × multiplicateur
+    #[serde(default = "prix_de_base")]
+    pub price_multiplier: f32,
+}
+
+/// Stock restant d'une boutique, sauvegardé avec le joueur
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShopState {
+    pub stock: BTreeMap<u32, u32>, // item_id -> quantité restante
+    /// Compteur d'actions du joueur au dernier réapprovisionnement
+    pub last_restock: u64,
+}
+
+fn reprise_par_defaut() -> f32 {
+    0.5
+}
+
+fn prix_de_base() -> f32 {
+    1.0
+}
+
+impl StockEntry {
+    /// Prix d'achat d'un exemplaire, jamais gratuit
+    pub fn price(&self, item: &Item) -> u32 {
+        ((item.value as f32 * self.price_multiplier).round() as u32).max(1)
+    }
+}
+
+impl Shop {
+    /// Somme proposée au joueur pour un objet, `None` si le marchand le refuse
+    pub fn sell_price(&self, item: &Item) -> Option<u32> {
+        if item.item_type == ItemType::ObjetDeQuete {
+            return None;
+        }
+        Some((item.value as f32 * self.sell_multiplier).floor() as u32)
+    }
+
+    /// Boutique pleine, telle que décrite dans les données
+    pub fn fresh_state(&self, now: u64) -> ShopState {
+        let mut stock = BTreeMap::new();
+        for entry in &self.stock {
+            *stock.entry(entry.item_id).or_insert(0) += entry.quantity;
+        }
+        ShopState { stock, last_restock: now }
+    }
+
+    /// État de la boutique à l'action `now`, réapprovisionnée si le délai est écoulé
+    pub fn state_at(&self, saved: Option<&ShopState>, now: u64) -> ShopState {
+        match saved {
+            Some(state) if self.restock_every == 0 || now.saturating_sub(state.last_restock) < self.restock_every => {
+                state.clone()
+            }
+            _ => self.fresh_state(now),
+        }
+    }
+}
+
+impl ShopState {
+    pub fn remaining(&self, item_id: u32) -> u32 {
+        self.stock.get(&item_id).copied().unwrap_or(0)
+    }
+
+    /// Retire un exemplaire du stock ; renvoie false s'il n'y en a plus
+    pub fn take(&mut self, item_id: u32) -> bool {
+        match self.stock.get_mut(&item_id) {
+            Some(quantity) if *quantity > 0 => {
+                *quantity -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn objet(id: u32, value: u32, item_type: ItemType) -> Item {
+        Item {
+            id,
+            name: format!("Objet {}", id),
+            description: "".into(),
+            value,
+            item_type,
+            utilisable: true,
+            health: 0,
+            strength: 0,
+            defense: 0,
+            agility: 0,
+            statuses: Vec::new(),
+        }
+    }
+
+    fn boutique() -> Shop {
+        serde_json::from_str(r#"{
+            "stock": [{"item_id": 1, "quantity": 2}, {"item_id": 3, "quantity": 1, "price_multiplier": 1.5}],
+            "restock_every": 10
+        }"#).unwrap()
+    }
+
+    #[test]
+    fn test_prix_d_achat_et_de_vente() {
+        let shop = boutique();
+        assert_eq!(shop.stock[0].price(&objet(1, 25, ItemType::Consommable)), 25);
+        assert_eq!(shop.stock[1].price(&objet(3, 75, ItemType::Armure)), 113);
+        assert_eq!(shop.stock[0].price(&objet(1, 0, ItemType::Consommable)), 1);
+
+        assert_eq!(shop.sell_price(&objet(2, 151, ItemType::Arme)), Some(75));
+        assert_eq!(shop.sell_price(&objet(5, 30, ItemType::ObjetDeQuete)), None);
+    }
+
+    #[test]
+    fn test_stock_et_reapprovisionnement() {
+        let shop = boutique();
+        let mut state = shop.state_at(None, 3);
+        assert!(state.take(1));
+        assert!(state.take(1));
+        assert!(!state.take(1));
+        assert!(!state.take(42));
+
+        // Pas encore l'heure : le stock vide est conservé
+        assert_eq!(shop.state_at(Some(&state), 12).remaining(1), 0);
+        let restocked = shop.state_at(Some(&state), 13);
+        assert_eq!(restocked.remaining(1), 2);
+        assert_eq!(restocked.last_restock, 13);
+    }
+}
@@ -0,0 +1,303 @@
+use crate::player::Attributes;
+use crate::rng::GameRng;
+use serde::{Deserialize, Serialize};
+
+/// Nature d'une altération d'état ; elle fixe son effet par tour et sa règle de cumul
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusKind {
+    /// `potency` dégâts par tour ; une nouvelle dose s'ajoute à l'intensité
+    Poison,
+    /// `potency` dégâts par tour et par plaie, jusqu'à `MAX_STACKS` plaies
+    Saignement,
+    /// `potency` points de vie rendus par tour
+    Regeneration,
+    /// Fait perdre le tour ; disparaît à la fin du combat
+    Etourdissement,
+    /// Bonus de stats (`modifiers`) tant que l'effet dure
+    Renforcement,
+    /// Malus de stats (`modifiers`) tant que l'effet dure
+    Affaiblissement,
+}
+
+/// Nombre maximum de plaies cumulées par un saignement
+pub const MAX_STACKS: u32 = 3;
+
+impl StatusKind {
+    pub fn name(&self) -> &str {
+        match self {
+            StatusKind::Poison => "Poison",
+            StatusKind::Saignement => "Saignement",
+            StatusKind::Regeneration => "Régénération",
+            StatusKind::Etourdissement => "Étourdissement",
+            StatusKind::Renforcement => "Renforcement",
+            StatusKind::Affaiblissement => "Affaiblissement",
+        }
+    }
+
+    pub fn icon(&self) -> &str {
+        match self {
+            StatusKind::Poison => "☠️",
+            StatusKind::Saignement => "🩸",
+            StatusKind::Regeneration => "🌿",
+            StatusKind::Etourdissement => "💫",
+            StatusKind::Renforcement => "💪",
+            StatusKind::Affaiblissement => "🥀",
+        }
+    }
+
+    /// Les altérations néfastes visent l'adversaire, les autres celui qui les lance
+    pub fn is_harmful(&self) -> bool {
+        matches!(self, StatusKind::Poison | StatusKind::Saignement | StatusKind::Etourdissement | StatusKind::Affaiblissement)
+    }
+
+    /// L'étourdissement ne dure que le temps du combat ; le reste est sauvegardé
+    pub fn survives_combat(&self) -> bool {
+        !matches!(self, StatusKind::Etourdissement)
+    }
+}
+
+/// Altération active, ex: `{ "kind": "poison", "duration": 3, "potency": 4 }`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    /// Tours restants (un tour de combat, ou une commande hors combat)
+    pub duration: u32,
+    /// Dégâts ou soins par tour selon la nature
+    #[serde(default)]
+    pub potency: i32,
+    /// Stats ajoutées tant que l'effet dure (renforcement, affaiblissement)
+    #[serde(default)]
+    pub modifiers: Attributes,
+    #[serde(default = "une_pile")]
+    pub stacks: u32,
+}
+
+/// Altération appliquée par une compétence, un coup de monstre ou un consommable
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusApplication {
+    #[serde(flatten)]
+    pub effect: StatusEffect,
+    #[serde(default = "toujours")]
+    pub chance: u32, // En %, 100 par défaut
+}
+
+fn une_pile() -> u32 {
+    1
+}
+
+fn toujours() -> u32 {
+    100
+}
+
+impl StatusEffect {
+    /// Variation de santé du porteur à chaque tour (négative pour les dégâts)
+    pub fn health_per_turn(&self) -> i32 {
+        match self.kind {
+            StatusKind::Poison => -self.potency,
+            StatusKind::Saignement => -self.potency * self.stacks as i32,
+            StatusKind::Regeneration => self.potency,
+            _ => 0,
+        }
+    }
+
+    /// Libellé court, ex: "☠️ Poison 4 (3 tours)"
+    pub fn describe(&self) -> String {
+        let intensite = match self.kind {
+            StatusKind::Poison | StatusKind::Regeneration => format!(" {}", self.potency),
+            StatusKind::Saignement if self.stacks > 1 => format!(" x{}", self.stacks),
+            StatusKind::Renforcement | StatusKind::Affaiblissement if !self.modifiers.is_zero() => {
+                format!(" ({})", self.modifiers.describe())
+            }
+            _ => String::new(),
+        };
+        let pluriel = if self.duration > 1 { "s" } else { "" };
+        format!("{} {}{} ({} tour{})", self.kind.icon(), self.kind.name(), intensite, self.duration, pluriel)
+    }
+
+    /// Incohérences de l'altération (durée, intensité)
+    pub fn problems(&self) -> Vec<String> {
+        let mut problemes = Vec::new();
+        if self.duration == 0 {
+            problemes.push(format!("{} : durée nulle", self.kind.name()));
+        }
+        if matches!(self.kind, StatusKind::Poison | StatusKind::Saignement | StatusKind::Regeneration) && self.potency <= 0 {
+            problemes.push(format!("{} : intensité {} invalide", self.kind.name(), self.potency));
+        }
+        if matches!(self.kind, StatusKind::Renforcement | StatusKind::Affaiblissement) && self.modifiers.is_zero() {
+            problemes.push(format!("{} : aucune stat modifiée", self.kind.name()));
+        }
+        problemes
+    }
+}
+
+impl StatusApplication {
+    pub fn problems(&self) -> Vec<String> {
+        let mut problemes = self.effect.problems();
+        if self.chance == 0 || self.chance > 100 {
+            problemes.push(format!("{} : probabilité {} hors de 1..=100", self.effect.kind.name(), self.chance));
+        }
+        problemes
+    }
+
+    /// Tire la probabilité d'application ; renvoie l'altération si elle prend
+    pub fn roll(&self, rng: &mut GameRng) -> Option<StatusEffect> {
+        (self.chance >= 100 || rng.chance(self.chance)).then(|| self.effect.clone())
+    }
+}
+
+/// Résultat d'un tour d'altérations
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct StatusTick {
+    pub damage: i32,
+    pub heal: i32,
+    /// Le porteur était étourdi au début du tour et le perd
+    pub stunned: bool,
+    pub expired: Vec<StatusKind>,
+}
+
+/// Altérations portées par le joueur ou un monstre, au plus une par nature
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Statuses {
+    pub effects: Vec<StatusEffect>,
+}
+
+impl Statuses {
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    pub fn get(&self, kind: StatusKind) -> Option<&StatusEffect> {
+        self.effects.iter().find(|effect| effect.kind == kind)
+    }
+
+    /// Ajoute une altération selon la règle de cumul de sa nature :
+    /// le poison s'intensifie, le saignement ajoute une plaie (jusqu'à `MAX_STACKS`),
+    /// les autres sont simplement rafraîchies. La durée retenue est toujours la plus longue.
+    pub fn apply(&mut self, effect: StatusEffect) {
+        let Some(actuel) = self.effects.iter_mut().find(|e| e.kind == effect.kind) else {
+            self.effects.push(effect);
+            return;
+        };
+        actuel.duration = actuel.duration.max(effect.duration);
+        match effect.kind {
+            StatusKind::Poison => actuel.potency += effect.potency,
+            StatusKind::Saignement => {
+                actuel.potency = actuel.potency.max(effect.potency);
+                actuel.stacks = (actuel.stacks + effect.stacks).min(MAX_STACKS);
+            }
+            _ => {
+                actuel.potency = actuel.potency.max(effect.potency);
+                actuel.modifiers = effect.modifiers;
+            }
+        }
+    }
+
+    /// Fait passer un tour : cumule dégâts et soins, puis retire les altérations épuisées
+    pub fn tick(&mut self) -> StatusTick {
+        let mut tick = StatusTick { stunned: self.get(StatusKind::Etourdissement).is_some(), ..StatusTick::default() };
+        for effect in &mut self.effects {
+            let variation = effect.health_per_turn();
+            if variation < 0 {
+                tick.damage -= variation;
+            } else {
+                tick.heal += variation;
+            }
+            effect.duration = effect.duration.saturating_sub(1);
+            if effect.duration == 0 {
+                tick.expired.push(effect.kind);
+            }
+        }
+        self.effects.retain(|effect| effect.duration > 0);
+        tick
+    }
+
+    /// Somme des bonus et malus de stats actifs
+    pub fn modifiers(&self) -> Attributes {
+        let mut total = Attributes::default();
+        for effect in &self.effects {
+            total.apply_delta(&effect.modifiers);
+        }
+        total
+    }
+
+    /// Retire les altérations limitées au combat
+    pub fn end_combat(&mut self) {
+        self.effects.retain(|effect| effect.kind.survives_combat());
+    }
+
+    /// Liste affichée à côté de la barre de vie, vide sans altération
+    pub fn describe(&self) -> String {
+        self.effects.iter().map(StatusEffect::describe).collect::<Vec<_>>().join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn effet(kind: StatusKind, duration: u32, potency: i32) -> StatusEffect {
+        StatusEffect { kind, duration, potency, modifiers: Attributes::default(), stacks: 1 }
+    }
+
+    #[test]
+    fn test_alterations_depuis_json() {
+        let applications: Vec<StatusApplication> = serde_json::from_str(r#"[
+            {"kind": "poison", "duration": 3, "potency": 4, "chance": 25},
+            {"kind": "renforcement", "duration": 5, "modifiers": {"strength": 4}},
+            {"kind": "etourdissement", "duration": 1}
+        ]"#).unwrap();
+
+        assert_eq!(applications[0].chance, 25);
+        assert_eq!(applications[0].effect.health_per_turn(), -4);
+        assert_eq!(applications[1].chance, 100);
+        assert_eq!(applications[1].effect.modifiers, Attributes { strength: 4, ..Attributes::default() });
+        assert!(applications.iter().all(|a| a.problems().is_empty()));
+        assert_eq!(applications[1].effect.describe(), "💪 Renforcement (+4 force) (5 tours)");
+
+        let vide: StatusApplication = serde_json::from_str(r#"{"kind": "affaiblissement", "duration": 0, "chance": 0}"#).unwrap();
+        assert_eq!(vide.problems().len(), 3);
+    }
+
+    #[test]
+    fn test_regles_de_cumul() {
+        let mut statuts = Statuses::default();
+        statuts.apply(effet(StatusKind::Poison, 3, 2));
+        statuts.apply(effet(StatusKind::Poison, 2, 3));
+        assert_eq!(statuts.get(StatusKind::Poison), Some(&effet(StatusKind::Poison, 3, 5)));
+
+        for _ in 0..4 {
+            statuts.apply(effet(StatusKind::Saignement, 2, 2));
+        }
+        assert_eq!(statuts.get(StatusKind::Saignement).unwrap().stacks, MAX_STACKS);
+
+        statuts.apply(effet(StatusKind::Regeneration, 4, 3));
+        statuts.apply(effet(StatusKind::Regeneration, 1, 3));
+        assert_eq!(statuts.get(StatusKind::Regeneration).unwrap().duration, 4);
+        assert_eq!(statuts.effects.len(), 3);
+    }
+
+    #[test]
+    fn test_tours_et_fin_de_combat() {
+        let mut statuts = Statuses::default();
+        statuts.apply(effet(StatusKind::Poison, 2, 4));
+        statuts.apply(effet(StatusKind::Regeneration, 3, 1));
+        statuts.apply(effet(StatusKind::Etourdissement, 1, 0));
+        statuts.apply(StatusEffect {
+            modifiers: Attributes { defense: -2, ..Attributes::default() },
+            ..effet(StatusKind::Affaiblissement, 5, 0)
+        });
+        assert_eq!(statuts.modifiers().defense, -2);
+
+        let tour = statuts.tick();
+        assert_eq!((tour.damage, tour.heal, tour.stunned), (4, 1, true));
+        assert_eq!(tour.expired, vec![StatusKind::Etourdissement]);
+        assert!(!statuts.tick().stunned);
+        assert!(statuts.get(StatusKind::Poison).is_none());
+
+        statuts.apply(effet(StatusKind::Etourdissement, 2, 0));
+        statuts.end_combat();
+        assert_eq!(statuts.describe(), "🌿 Régénération 1 (1 tour), 🥀 Affaiblissement (-2 défense) (3 tours)");
+    }
+}
@@ -1,8 +1,10 @@
 use crate::npc::Npc;
 use crate::monster::Monster;
-use serde::Deserialize;
+use crate::io::GameIo;
+use crate::ecrire;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[serde(rename_all = "PascalCase")]
 pub enum Direction {
     North,
@@ -11,14 +13,133 @@ pub enum Direction {
     West,
 }
 
+impl Direction {
+    /// Convertit un nom de direction (anglais ou français) en `Direction`
+    pub fn parse(name: &str) -> Option<Direction> {
+        match name.to_lowercase().as_str() {
+            "north" | "nord" => Some(Direction::North),
+            "south" | "sud" => Some(Direction::South),
+            "east" | "est" => Some(Direction::East),
+            "west" | "ouest" => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+/// Sortie d'une zone telle que déclarée dans zones.json
+#[derive(Debug, Deserialize, Clone)]
+pub struct ConnectionRaw {
+    pub direction: String, // Sera convertie en `Direction`
+    pub to: u32,           // ID de la zone de destination
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct ZoneRaw {
     pub id: u32,
     pub name: String,
     pub description: String,
-    pub connections: Vec<String>, // Les directions seront converties
+    pub connections: Vec<ConnectionRaw>,
     pub npcs: Vec<u32>, // IDs des NPCs
-    pub monsters: Option<Vec<u32>>, // IDs des monstres (optionnel)
+    pub monsters: Option<Vec<MonsterSpawn>>, // Monstres placés (optionnel)
+    #[serde(default)]
+    pub respawn: u64, // Actions avant le retour d'un monstre vaincu (0 : jamais)
+    #[serde(default)]
+    pub encounters: Vec<EncounterRaw>, // Groupes de monstres affrontés ensemble
+    #[serde(default)]
+    pub ambush: u32, // Chance (en %) d'être attaqué par surprise en arrivant
+}
+
+/// Groupe de rencontre, ex: `{ "name": "Meute de Loups", "monsters": [1, 1, 1], "respawn": 30 }`
+#[derive(Debug, Deserialize, Clone)]
+pub struct EncounterRaw {
+    pub name: String,
+    pub monsters: Vec<MonsterSpawn>,
+    #[serde(default)]
+    pub respawn: Option<u64>, // Délai du groupe, sinon celui de la zone
+}
+
+impl ZoneRaw {
+    /// Tous les monstres placés dans la zone, isolés ou en groupe
+    pub fn spawns(&self) -> impl Iterator<Item = &MonsterSpawn> {
+        self.monsters.iter().flatten().chain(self.encounters.iter().flat_map(|encounter| &encounter.monsters))
+    }
+}
+
+/// Monstre placé dans une zone : un ID, ou `{ "id": 3, "respawn": 40 }`
+/// pour remplacer le délai de réapparition de la zone
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum MonsterSpawn {
+    Id(u32),
+    Detail {
+        id: u32,
+        #[serde(default)]
+        respawn: Option<u64>,
+    },
+}
+
+impl MonsterSpawn {
+    pub fn id(&self) -> u32 {
+        match *self {
+            MonsterSpawn::Id(id) | MonsterSpawn::Detail { id, .. } => id,
+        }
+    }
+
+    /// Délai propre au monstre, sinon celui de la zone
+    pub fn respawn_delay(&self, zone_respawn: u64) -> u64 {
+        match *self {
+            MonsterSpawn::Detail { respawn: Some(respawn), .. } => respawn,
+            _ => zone_respawn,
+        }
+    }
+}
+
+impl From<u32> for MonsterSpawn {
+    fn from(id: u32) -> Self {
+        MonsterSpawn::Id(id)
+    }
+}
+
+/// Identité d'un monstre placé, stable quand zones.json évolue : l'ID du monstre
+/// et son rang parmi les monstres de même ID de sa liste (isolés, ou groupe nommé)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpawnId {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    pub monster_id: u32,
+    pub occurrence: u32,
+}
+
+/// Monstre vaincu en attente de réapparition
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DefeatedMonster {
+    #[serde(flatten)]
+    pub spawn: SpawnId,
+    pub defeated_at: u64, // Action du joueur à laquelle il a été vaincu
+}
+
+/// Monstres vaincus d'une zone, sauvegardés avec le joueur
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZoneState {
+    pub defeated: Vec<DefeatedMonster>,
+}
+
+impl ZoneState {
+    pub fn is_defeated(&self, spawn: &SpawnId) -> bool {
+        self.defeated.iter().any(|defeat| defeat.spawn == *spawn)
+    }
+
+    pub fn record_defeat(&mut self, spawn: SpawnId, defeated_at: u64) {
+        self.defeated.retain(|defeat| defeat.spawn != spawn);
+        self.defeated.push(DefeatedMonster { spawn, defeated_at });
+    }
+}
+
+/// Combat proposé dans une zone : un monstre isolé ou un groupe de rencontre
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Encounter {
+    pub name: String,
+    pub slots: Vec<usize>, // Emplacements des monstres dans `Zone.monsters`
 }
 
 #[derive(Debug, Clone)]
@@ -26,24 +147,25 @@ pub struct Zone {
     pub id: u32,
     pub name: String,
     pub description: String,
-    pub connections: Vec<Direction>,
+    pub connections: Vec<(Direction, u32)>, // (direction, ID de la zone voisine), dans l'ordre de zones.json
     pub npcs: Vec<Npc>,
     pub monsters: Vec<Monster>,
+    pub respawn_delays: Vec<u64>, // Délai de réapparition de chaque monstre (0 : jamais)
+    pub spawn_ids: Vec<SpawnId>, // Identité de chaque monstre, pour la sauvegarde
+    pub groups: Vec<Encounter>, // Groupes de rencontre, leurs membres suivent les monstres isolés
+    pub ambush: u32,
 }
 
 impl Zone {
     pub fn from_raw(raw: ZoneRaw, all_npcs: &[Npc], all_monsters: &[Monster]) -> Self {
-        // Convertir les strings en directions
-        let connections = raw.connections
-            .iter()
-            .filter_map(|s| match s.to_lowercase().as_str() {
-                "north" | "nord" => Some(Direction::North),
-                "south" | "sud" => Some(Direction::South),
-                "east" | "est" => Some(Direction::East),
-                "west" | "ouest" => Some(Direction::West),
-                _ => None,
-            })
-            .collect();
+        // Convertir les strings en directions, dans l'ordre des données ;
+        // une direction déclarée deux fois garde sa première sortie
+        let mut connections: Vec<(Direction, u32)> = Vec::new();
+        for (dir, to) in raw.connections.iter().filter_map(|c| Direction::parse(&c.direction).map(|dir| (dir, c.to))) {
+            if !connections.iter().any(|(existante, _)| *existante == dir) {
+                connections.push((dir, to));
+            }
+        }
 
         // Récupérer les NPCs correspondants
         let npcs = raw.npcs
@@ -51,61 +173,130 @@ impl Zone {
             .filter_map(|npc_id| all_npcs.iter().find(|npc| npc.id == *npc_id).cloned())
             .collect();
 
-        // Récupérer les monstres correspondants
-        let monsters = raw.monsters
-            .unwrap_or_default()
-            .iter()
-            .filter_map(|monster_id| all_monsters.iter().find(|monster| monster.id == *monster_id).cloned())
-            .collect();
-
-        Zone {
+        let mut zone = Zone {
             id: raw.id,
             name: raw.name,
             description: raw.description,
             connections,
             npcs,
-            monsters,
+            monsters: Vec::new(),
+            respawn_delays: Vec::new(),
+            spawn_ids: Vec::new(),
+            groups: Vec::new(),
+            ambush: raw.ambush,
+        };
+
+        // Récupérer les monstres correspondants ; les membres des groupes prennent les emplacements suivants
+        zone.placer(raw.monsters.as_deref().unwrap_or_default(), None, raw.respawn, all_monsters);
+        for encounter in &raw.encounters {
+            let premier = zone.monsters.len();
+            zone.placer(&encounter.monsters, Some(&encounter.name), encounter.respawn.unwrap_or(raw.respawn), all_monsters);
+            if zone.monsters.len() > premier {
+                zone.groups.push(Encounter { name: encounter.name.clone(), slots: (premier..zone.monsters.len()).collect() });
+            }
+        }
+        zone
+    }
+
+    /// Ajoute les monstres d'une liste (isolés ou groupe) à la suite des emplacements de la zone.
+    /// Le rang compte aussi les groupes homonymes déjà placés : chaque identité reste unique.
+    fn placer(&mut self, spawns: &[MonsterSpawn], group: Option<&str>, respawn: u64, all_monsters: &[Monster]) {
+        for spawn in spawns {
+            let Some(monster) = all_monsters.iter().find(|monster| monster.id == spawn.id()) else {
+                continue;
+            };
+            let group = group.map(str::to_string);
+            let occurrence = self.spawn_ids
+                .iter()
+                .filter(|id| id.group == group && id.monster_id == spawn.id())
+                .count() as u32;
+            self.monsters.push(monster.clone());
+            self.respawn_delays.push(spawn.respawn_delay(respawn));
+            self.spawn_ids.push(SpawnId { group, monster_id: spawn.id(), occurrence });
         }
     }
 
-    pub fn afficher(&self) {
-        println!("🗺️  Zone [{}] : {}", self.id, self.name);
-        println!("📍 {}", self.description);
+    /// Emplacement du monstre placé, `None` s'il n'existe plus dans zones.json
+    pub fn slot_of(&self, spawn: &SpawnId) -> Option<usize> {
+        self.spawn_ids.iter().position(|id| id == spawn)
+    }
+
+    fn in_group(&self, slot: usize) -> bool {
+        self.groups.iter().any(|group| group.slots.contains(&slot))
+    }
+
+    /// Combats disponibles : chaque monstre isolé vivant, puis chaque groupe
+    /// dont il reste au moins un membre (seuls les membres vivants sont affrontés)
+    pub fn encounters(&self) -> Vec<Encounter> {
+        let vivant = |slot: &usize| self.monsters[*slot].is_alive();
+        let mut encounters: Vec<Encounter> = (0..self.monsters.len())
+            .filter(|slot| vivant(slot) && !self.in_group(*slot))
+            .map(|slot| Encounter { name: self.monsters[slot].name.clone(), slots: vec![slot] })
+            .collect();
+        for group in &self.groups {
+            let slots: Vec<usize> = group.slots.iter().copied().filter(vivant).collect();
+            if !slots.is_empty() {
+                let pluriel = if slots.len() > 1 { "s" } else { "" };
+                encounters.push(Encounter { name: format!("{} ({} monstre{})", group.name, slots.len(), pluriel), slots });
+            }
+        }
+        encounters
+    }
+
+    /// Renvoie l'ID de la zone atteinte en sortant dans `direction`
+    pub fn destination(&self, direction: Direction) -> Option<u32> {
+        self.connections.iter().find(|(dir, _)| *dir == direction).map(|(_, to)| *to)
+    }
+
+    /// Directions des sorties, dans l'ordre où elles sont proposées au joueur
+    pub fn directions(&self) -> impl Iterator<Item = Direction> + '_ {
+        self.connections.iter().map(|(dir, _)| *dir)
+    }
+
+    pub fn afficher(&self, io: &mut dyn GameIo) {
+        ecrire!(io, "🗺️  Zone [{}] : {}", self.id, self.name);
+        ecrire!(io, "📍 {}", self.description);
 
         if self.connections.is_empty() {
-            println!("🚫 Aucune sortie disponible.");
+            ecrire!(io, "🚫 Aucune sortie disponible.");
         } else {
-            println!("🧭 Sorties disponibles :");
-            for dir in &self.connections {
+            ecrire!(io, "🧭 Sorties disponibles :");
+            for dir in self.directions() {
                 let emoji = match dir {
                     Direction::North => "⬆️",
                     Direction::South => "⬇️",
                     Direction::East => "➡️",
                     Direction::West => "⬅️",
                 };
-                println!("   {} {:?}", emoji, dir);
+                ecrire!(io, "   {} {:?}", emoji, dir);
             }
         }
 
         if self.npcs.is_empty() {
-            println!("👤 Aucun personnage dans cette zone.");
+            ecrire!(io, "👤 Aucun personnage dans cette zone.");
         } else {
-            println!("👥 Personnages présents :");
+            ecrire!(io, "👥 Personnages présents :");
             for npc in &self.npcs {
-                println!("   🧙 {} : {}", npc.name(), npc.description());
+                ecrire!(io, "   🧙 {} : {}", npc.name(), npc.description());
             }
         }
 
-        if self.monsters.is_empty() {
-            println!("🕊️  Aucun monstre dans cette zone.");
+        // Les monstres vaincus ont quitté la zone jusqu'à leur réapparition
+        if !self.monsters.iter().any(|monster| monster.is_alive()) {
+            ecrire!(io, "🕊️  Aucun monstre dans cette zone.");
         } else {
-            println!("⚔️  Monstres présents :");
-            for monster in &self.monsters {
-                println!("   👹 {} : {}", monster.name, monster.description);
-                if monster.is_alive() {
-                    println!("     {}", monster.health_bar());
-                } else {
-                    println!("     💀 Vaincu");
+            ecrire!(io, "⚔️  Monstres présents :");
+            for (_, monster) in self.monsters.iter().enumerate().filter(|(slot, monster)| monster.is_alive() && !self.in_group(*slot)) {
+                ecrire!(io, "   👹 {} : {}", monster.name, monster.description);
+                ecrire!(io, "     {}", monster.health_bar());
+            }
+            for group in &self.groups {
+                let membres: Vec<&Monster> = group.slots.iter().map(|slot| &self.monsters[*slot]).filter(|m| m.is_alive()).collect();
+                if !membres.is_empty() {
+                    ecrire!(io, "   🐾 {} :", group.name);
+                    for monster in membres {
+                        ecrire!(io, "     👹 {} {}", monster.name, monster.health_bar());
+                    }
                 }
             }
         }
@@ -118,23 +309,53 @@ mod tests {
     use crate::monster::Monster;
     use crate::quest::Quest;
 
+    fn connexion(direction: &str, to: u32) -> ConnectionRaw {
+        ConnectionRaw { direction: direction.to_string(), to }
+    }
+
+    fn loup() -> Monster {
+        Monster {
+            id: 1,
+            name: "Loup".to_string(),
+            description: "".to_string(),
+            max_health: 10,
+            current_health: 10,
+            strength: 1,
+            defense: 0,
+            agility: 1,
+            loot: vec![],
+            experience: 1,
+            gold: Default::default(),
+            on_hit: vec![],
+            statuses: Default::default(),
+        }
+    }
+
     #[test]
     fn test_directions_francaises() {
         let raw = ZoneRaw {
             id: 1,
             name: "Place du Village".to_string(),
             description: "La place centrale du village".to_string(),
-            connections: vec!["nord".to_string(), "sud".to_string(), "est".to_string(), "ouest".to_string()],
+            connections: vec![
+                connexion("nord", 2),
+                connexion("sud", 3),
+                connexion("est", 4),
+                connexion("ouest", 5),
+            ],
             npcs: vec![],
             monsters: None,
+            respawn: 0,
+            encounters: vec![],
+            ambush: 0,
         };
 
         let zone = Zone::from_raw(raw, &[], &[]);
         assert_eq!(zone.connections.len(), 4);
-        assert!(matches!(zone.connections[0], Direction::North));
-        assert!(matches!(zone.connections[1], Direction::South));
-        assert!(matches!(zone.connections[2], Direction::East));
-        assert!(matches!(zone.connections[3], Direction::West));
+        assert_eq!(zone.destination(Direction::North), Some(2));
+        assert_eq!(zone.destination(Direction::South), Some(3));
+        assert_eq!(zone.destination(Direction::East), Some(4));
+        assert_eq!(zone.destination(Direction::West), Some(5));
     }
 
     #[test]
@@ -143,13 +364,54 @@ mod tests {
             id: 2,
             name: "Zone Corrompue".to_string(),
             description: "Données partiellement corrompues".to_string(),
-            connections: vec!["nord".to_string(), "direction_invalide".to_string(), "sud".to_string()],
+            connections: vec![connexion("nord", 1), connexion("direction_invalide", 3), connexion("sud", 4)],
             npcs: vec![],
             monsters: None,
+            respawn: 0,
+            encounters: vec![],
+            ambush: 0,
         };
 
         let zone = Zone::from_raw(raw, &[], &[]);
         assert_eq!(zone.connections.len(), 2); // Seuls "nord" et "sud" sont valides
+        assert_eq!(zone.destination(Direction::East), None);
+    }
+
+    #[test]
+    fn test_sorties_dans_l_ordre_des_donnees() {
+        let raw = ZoneRaw {
+            id: 12,
+            name: "Ferme".to_string(),
+            description: String::new(),
+            connections: vec![connexion("sud", 11), connexion("ouest", 2), connexion("nord", 21), connexion("sud", 99)],
+            npcs: vec![],
+            monsters: None,
+            respawn: 0,
+            encounters: vec![],
+            ambush: 0,
+        };
+
+        let zone = Zone::from_raw(raw, &[], &[]);
+        let directions: Vec<Direction> = zone.directions().collect();
+        assert_eq!(directions, vec![Direction::South, Direction::West, Direction::North]);
+        assert_eq!(zone.destination(Direction::South), Some(11)); // Le doublon est ignoré
+    }
+
+    #[test]
+    fn test_connexions_depuis_json() {
+        let json = r#"{
+            "id": 1,
+            "name": "Place du Village",
+            "description": "Le centre du village",
+            "connections": [{"direction": "North", "to": 2}, {"direction": "East", "to": 11}],
+            "npcs": []
+        }"#;
+
+        let raw: ZoneRaw = serde_json::from_str(json).expect("Zone JSON invalide");
+        let zone = Zone::from_raw(raw, &[], &[]);
+        assert_eq!(zone.destination(Direction::North), Some(2));
+        assert_eq!(zone.destination(Direction::East), Some(11));
+        assert_eq!(zone.destination(Direction::South), None);
     }
 
     #[test]
@@ -159,7 +421,12 @@ mod tests {
             name: "Sauver le Village".to_string(),
             description: "Éliminez les monstres qui menacent le village".to_string(),
             objet_requis_id: None,
+            objectives: vec![],
             completed: false,
+            reward: Default::default(),
+            prerequisites: Default::default(),
+            follow_ups: vec![],
+            dialogue: Default::default(),
         };
 
         let pnj = Npc {
@@ -168,6 +435,8 @@ mod tests {
             description: "Le dirigeant du village".to_string(),
             dialogues: vec!["Aidez-nous, brave aventurier !".to_string()],
             quests: vec![quete],
+            dialogue_tree: None,
+            shop: None,
         };
 
         let monstre = Monster {
@@ -179,17 +448,23 @@ mod tests {
             strength: 12,
             defense: 3,
             agility: 10,
-            loot: vec![5],
+            loot: vec![5.into()],
             experience: 20,
+            gold: Default::default(),
+            on_hit: vec![],
+            statuses: Default::default(),
         };
 
         let raw = ZoneRaw {
             id: 3,
             name: "Lisière de la Forêt".to_string(),
             description: "Où le village rencontre la nature sauvage".to_string(),
-            connections: vec!["nord".to_string(), "ouest".to_string()],
+            connections: vec![connexion("nord", 4), connexion("ouest", 1)],
             npcs: vec![1],
-            monsters: Some(vec![1]),
+            monsters: Some(vec![1.into()]),
+            respawn: 0,
+            encounters: vec![],
+            ambush: 0,
         };
 
         let zone = Zone::from_raw(raw, &[pnj], &[monstre]);
@@ -205,13 +480,92 @@ mod tests {
             id: 4,
             name: "Zone Vide".to_string(),
             description: "Aucune entité trouvée".to_string(),
-            connections: vec!["est".to_string()],
+            connections: vec![connexion("est", 1)],
             npcs: vec![999], // ID inexistant
-            monsters: Some(vec![888]), // ID inexistant
+            monsters: Some(vec![888.into()]), // ID inexistant
+            respawn: 0,
+            encounters: vec![],
+            ambush: 0,
         };
 
         let zone = Zone::from_raw(raw, &[], &[]);
         assert_eq!(zone.npcs.len(), 0);
         assert_eq!(zone.monsters.len(), 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_delais_de_reapparition() {
+        let raw: ZoneRaw = serde_json::from_str(r#"{
+            "id": 5, "name": "Crypte", "description": "", "connections": [], "npcs": [],
+            "monsters": [1, {"id": 1, "respawn": 40}, {"id": 999}],
+            "respawn": 12
+        }"#).unwrap();
+
+        let zone = Zone::from_raw(raw, &[], &[loup()]);
+        assert_eq!(zone.monsters.len(), 2);
+        assert_eq!(zone.respawn_delays, vec![12, 40]);
+    }
+
+    #[test]
+    fn test_identite_stable_des_monstres() {
+        let zone = |monsters: &str| {
+            let json = format!(r#"{{"id": 7, "name": "Crypte", "description": "", "connections": [], "npcs": [], "monsters": {}}}"#, monsters);
+            Zone::from_raw(serde_json::from_str(&json).unwrap(), &[], &[loup(), Monster { id: 2, ..loup() }])
+        };
+        let avant = zone("[1, 1]");
+        let second = avant.spawn_ids[1].clone();
+        assert_eq!(second, SpawnId { group: None, monster_id: 1, occurrence: 1 });
+
+        // Un monstre ajouté devant ne décale pas l'identité des autres
+        let apres = zone("[2, 1, 1]");
+        assert_eq!(apres.slot_of(&second), Some(2));
+        assert_eq!(zone("[1]").slot_of(&second), None);
+    }
+
+    #[test]
+    fn test_groupes_de_rencontre() {
+        let raw: ZoneRaw = serde_json::from_str(r#"{
+            "id": 6, "name": "Tanière", "description": "", "connections": [], "npcs": [],
+            "monsters": [1],
+            "respawn": 12,
+            "encounters": [
+                {"name": "Meute", "monsters": [1, {"id": 1, "respawn": 5}], "respawn": 30},
+                {"name": "Fantômes", "monsters": [999]}
+            ]
+        }"#).unwrap();
+        assert_eq!(raw.spawns().count(), 4);
+
+        let mut zone = Zone::from_raw(raw, &[], &[loup()]);
+        assert_eq!(zone.respawn_delays, vec![12, 30, 5]);
+        assert_eq!(zone.spawn_ids[2], SpawnId { group: Some("Meute".into()), monster_id: 1, occurrence: 1 });
+        assert_eq!(zone.groups, vec![Encounter { name: "Meute".into(), slots: vec![1, 2] }]);
+        assert_eq!(zone.encounters()[1], Encounter { name: "Meute (2 monstres)".into(), slots: vec![1, 2] });
+
+        // Seuls les membres encore debout sont proposés
+        zone.monsters[1].current_health = 0;
+        assert_eq!(zone.encounters()[1], Encounter { name: "Meute (1 monstre)".into(), slots: vec![2] });
+        zone.monsters[2].current_health = 0;
+        assert_eq!(zone.encounters().len(), 1);
+    }
+
+    #[test]
+    fn test_groupes_homonymes_distincts() {
+        let raw: ZoneRaw = serde_json::from_str(r#"{
+            "id": 6, "name": "Tanière", "description": "", "connections": [], "npcs": [],
+            "encounters": [
+                {"name": "Meute", "monsters": [1, 1]},
+                {"name": "Meute", "monsters": [1]}
+            ]
+        }"#).unwrap();
+        let zone = Zone::from_raw(raw, &[], &[loup()]);
+
+        // Le second groupe prolonge les rangs du premier au lieu de reprendre ses identités
+        let seconde_meute = SpawnId { group: Some("Meute".into()), monster_id: 1, occurrence: 2 };
+        assert_eq!(zone.spawn_ids[2], seconde_meute);
+        assert_eq!(zone.slot_of(&seconde_meute), Some(2));
+
+        let mut state = ZoneState::default();
+        state.record_defeat(zone.spawn_ids[2].clone(), 4);
+        assert!(!state.is_defeated(&zone.spawn_ids[0]));
+    }
+}
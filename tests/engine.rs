@@ -0,0 +1,618 @@
+use rpg::{
+    load_game_data, Attributes, Direction, Game, GameConfig, GameRng, Player, QuestStatus, ScriptedIo, SpawnId, SAVE_VERSION, StatusEffect, StatusKind,
+    ValidationMode, START_ZONE_ID,
+};
+
+/// Graine commune aux parties de test : les combats scriptés se déroulent toujours de la même façon
+const GRAINE_DE_TEST: u64 = 42;
+
+fn config_de_test(nom_sauvegarde: &str) -> GameConfig {
+    GameConfig {
+        save_dir: std::env::temp_dir().to_string_lossy().into_owned(),
+        save_file: format!("{}_{}.json", nom_sauvegarde, std::process::id()),
+        ..GameConfig::default()
+    }
+}
+
+fn partie_scriptee(nom_sauvegarde: &str, io: &ScriptedIo) -> Game {
+    let config = config_de_test(nom_sauvegarde);
+    let (data, issues) = load_game_data(&config.data_dir, ValidationMode::Strict).expect("Données du jeu");
+    assert!(issues.is_empty());
+
+    let mut player = Player::new("Bot".to_string(), Attributes::new(100, 10, 5, 5));
+    player.rng = GameRng::new(GRAINE_DE_TEST);
+    Game::from_parts(config, data, player, Box::new(io.clone())).expect("Création de la partie")
+}
+
+fn partie_de_test(nom_sauvegarde: &str) -> Game {
+    partie_scriptee(nom_sauvegarde, &ScriptedIo::default())
+}
+
+#[test]
+fn test_deplacement_par_commande() {
+    let mut game = partie_de_test("rpg_engine_deplacement");
+    assert_eq!(game.player().current_zone_id, START_ZONE_ID);
+
+    let nord = game.current_zone().unwrap().destination(Direction::North).unwrap();
+    // Les sorties sont listées en premier dans le menu, dans l'ordre de zones.json
+    assert!(game.traiter_choix("1").unwrap());
+    assert_eq!(game.player().current_zone_id, nord);
+}
+
+#[test]
+fn test_commande_quitter() {
+    let mut game = partie_de_test("rpg_engine_quitter");
+    assert!(!game.traiter_choix("quit").unwrap());
+}
+
+#[test]
+fn test_sauvegarde_a_l_emplacement_configure() {
+    let game = partie_de_test("rpg_engine_sauvegarde");
+    game.sauvegarder().unwrap();
+
+    let path = game.config().save_path();
+    let loaded = Player::load_character(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.nom, "Bot");
+}
+
+#[test]
+fn test_sauvegarde_d_un_autre_monde_refusee() {
+    let config = GameConfig::default();
+    let (data, _) = load_game_data(&config.data_dir, ValidationMode::Lenient).unwrap();
+    let mut player = Player::new("Perdu".to_string(), Attributes::new(10, 1, 1, 1));
+    player.current_zone_id = 999;
+
+    assert!(matches!(
+        Game::from_parts(config, data, player, Box::new(ScriptedIo::default())),
+        Err(rpg::GameError::UnknownEntity { entity: "zone", .. })
+    ));
+}
+
+#[test]
+fn test_ancienne_sauvegarde_sans_mana() {
+    let config = config_de_test("rpg_engine_sans_mana");
+    let (data, _) = load_game_data(&config.data_dir, ValidationMode::Strict).unwrap();
+    let mut player = Player::new("Bot".to_string(), Attributes::new(100, 10, 5, 5));
+    player.classe = "Mage".to_string();
+    player.level = 3;
+    player.save_version = 0;
+
+    // 80 au niveau 1, +8 par niveau
+    let game = Game::from_parts(config.clone(), data.clone(), player, Box::new(ScriptedIo::default())).unwrap();
+    assert_eq!(game.player().base_stats.mana, 96);
+    assert_eq!(game.player().current_mana, 96);
+    assert_eq!(game.player().save_version, SAVE_VERSION);
+
+    // Une sauvegarde à jour garde son mana, même nul
+    let mut player = Player::new("Bot".to_string(), Attributes::new(100, 10, 5, 5));
+    player.classe = "Mage".to_string();
+    let game = Game::from_parts(config, data, player, Box::new(ScriptedIo::default())).unwrap();
+    assert_eq!(game.player().base_stats.mana, 0);
+}
+
+#[test]
+fn test_partie_complete_scriptee() {
+    // Nord vers la forêt, combat contre le premier monstre, fuite, puis quitter
+    let io = ScriptedIo::new(["1", "3", "2", "quit"]);
+    let mut game = partie_scriptee("rpg_engine_script", &io);
+    game.run().unwrap();
+    std::fs::remove_file(game.config().save_path()).ok();
+
+    assert!(io.output_contains("Vous arrivez à : Forêt du Nord"));
+    assert!(io.output_contains("Vous engagez le combat contre Loup Sauvage"));
+    assert!(io.output_contains("Vous fuyez le combat"));
+    assert!(io.output_contains("Merci d'avoir joué"));
+    assert_eq!(io.remaining_inputs(), 0);
+}
+
+#[test]
+fn test_fin_de_script_termine_la_partie() {
+    let io = ScriptedIo::new(["aide"]);
+    let mut game = partie_scriptee("rpg_engine_fin_script", &io);
+    game.run().unwrap();
+    std::fs::remove_file(game.config().save_path()).ok();
+    assert!(io.output_contains("📖 Aide"));
+}
+
+#[test]
+fn test_creation_de_personnage_scriptee() {
+    let config = config_de_test("rpg_engine_creation");
+    std::fs::remove_file(config.save_path()).ok();
+
+    let io = ScriptedIo::new(["Alice", "9", "1", "quit"]);
+    let mut game = Game::new(config, Box::new(io.clone())).unwrap();
+    game.run().unwrap();
+    std::fs::remove_file(game.config().save_path()).unwrap();
+
+    assert_eq!(game.player().nom, "Alice");
+    assert!(io.output_contains("❌ Choix invalide, réessayez."));
+    assert!(io.output_contains("Personnage créé avec succès"));
+}
+
+#[test]
+fn test_meme_graine_meme_partie() {
+    let jouer = |nom: &str| {
+        // Nord vers la forêt puis attaques répétées contre le loup
+        let mut commandes = vec!["1", "3"];
+        commandes.extend(std::iter::repeat_n("1", 30));
+        let io = ScriptedIo::new(commandes);
+
+        let mut config = config_de_test(nom);
+        config.seed = Some(2024);
+        let (data, _) = load_game_data(&config.data_dir, ValidationMode::Strict).unwrap();
+        let player = Player::new("Bot".to_string(), Attributes::new(100, 10, 5, 5));
+        let mut game = Game::from_parts(config, data, player, Box::new(io.clone())).unwrap();
+        game.run().unwrap();
+        std::fs::remove_file(game.config().save_path()).ok();
+        io.output()
+    };
+
+    let premiere = jouer("rpg_engine_graine_a");
+    assert!(premiere.iter().any(|line| line.contains("Graine de la partie : 2024")));
+    assert_eq!(premiere, jouer("rpg_engine_graine_b"));
+}
+
+#[test]
+fn test_quetes_suivies_dans_le_journal() {
+    let io = ScriptedIo::new(["o", "o"]);
+    let mut game = partie_scriptee("rpg_engine_journal", &io);
+
+    // Place du Village : [1] Nord, [2] Est, [3] Gérard, [4] Garde Royal
+    game.traiter_choix("3").unwrap();
+    assert_eq!(game.player().journal.status(1), Some(QuestStatus::Acceptee));
+
+    // La patrouille n'a pas d'objectif : rendue dès la première conversation, une seule fois
+    game.traiter_choix("4").unwrap();
+    game.traiter_choix("4").unwrap();
+    assert_eq!(game.player().journal.status(3), Some(QuestStatus::Rendue));
+    assert_eq!(game.player().gold, 20);
+    assert_eq!(game.player().experience, 30);
+    assert!(io.output_contains("Grâce à toi, le village dort tranquille."));
+
+    game.sauvegarder().unwrap();
+    let path = game.config().save_path();
+    let loaded = Player::load_character(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.journal, game.player().journal);
+}
+
+#[test]
+fn test_recompense_de_quete_detaillee() {
+    // Dialogue : [2] la clé, [1] la voici ; puis la quête est proposée
+    let io = ScriptedIo::new(["2", "1", "o"]);
+    let mut game = partie_scriptee("rpg_engine_recompense", &io);
+    let cle = game.items().iter().find(|item| item.id == 4).unwrap().clone();
+    game.player_mut().inventaire.push(cle);
+    let agilite = game.player().base_stats.agility;
+
+    // Maison d'Elara (Est) : [1] Ouest, [2] Nord, [3] Elara
+    game.traiter_choix("2").unwrap();
+    game.traiter_choix("3").unwrap();
+
+    assert!(io.output_contains("🪙 30 pièces d'or"));
+    assert!(io.output_contains("📦 Amulette de Vitesse x1"));
+    assert!(io.output_contains("💪 Bonus permanent : +1 agilité"));
+    assert_eq!(game.player().gold, 30);
+    assert_eq!(game.player().experience, 60);
+    assert_eq!(game.player().base_stats.agility, agilite + 1);
+    assert!(game.player().inventaire.iter().all(|item| item.id != 4));
+}
+
+#[test]
+fn test_quete_a_objectifs_multiples() {
+    let io = ScriptedIo::new(["o"]);
+    let mut game = partie_scriptee("rpg_engine_objectifs", &io);
+    game.player_mut().add_monster_kill(5); // Avant l'acceptation : ne compte pas
+    game.player_mut().current_zone_id = 12;
+
+    // Ferme de Tom : [1] Sud, [2] Ouest, [3] Nord, [4] Tom
+    game.traiter_choix("4").unwrap();
+    assert_eq!(game.player().journal.status(4), Some(QuestStatus::Acceptee));
+    assert!(io.output_contains("⬜ Vaincre Bandit des Routes : 0/2"));
+
+    game.player_mut().add_monster_kill(5);
+    game.player_mut().add_monster_kill(5);
+
+    // Place du Village : [4] Garde Royal, dont on décline la patrouille
+    game.player_mut().current_zone_id = START_ZONE_ID;
+    io.push_input("n");
+    game.traiter_choix("4").unwrap();
+    assert_eq!(game.player().journal.status(3), None);
+    assert_eq!(game.player().journal.status(4), Some(QuestStatus::Terminee));
+    assert!(io.output_contains("Quête 'Menace sur la Ferme' : objectifs remplis"));
+
+    game.player_mut().current_zone_id = 12;
+    game.traiter_choix("4").unwrap();
+    assert_eq!(game.player().journal.status(4), Some(QuestStatus::Rendue));
+    assert_eq!(game.player().gold, 40);
+}
+
+#[test]
+fn test_chaine_de_quetes() {
+    let io = ScriptedIo::new(["o", "2", "1", "o", "2", "o"]);
+    let mut game = partie_scriptee("rpg_engine_chaine", &io);
+
+    // Le Serment du Paladin n'est proposé ni aux autres classes, ni avant la patrouille
+    game.traiter_choix("4").unwrap();
+    assert_eq!(game.player().journal.status(3), Some(QuestStatus::Rendue));
+    assert_eq!(game.player().journal.status(6), None);
+
+    // Elara propose la suite de 'La Clé Perdue' une fois celle-ci rendue
+    let cle = game.items().iter().find(|item| item.id == 4).unwrap().clone();
+    game.player_mut().inventaire.push(cle);
+    game.player_mut().current_zone_id = 11;
+    game.traiter_choix("3").unwrap();
+    assert!(io.output_contains("Elara la Sage a une nouvelle quête pour vous"));
+    assert_eq!(game.player().journal.status(5), None);
+
+    game.traiter_choix("3").unwrap();
+    assert_eq!(game.player().journal.status(5), Some(QuestStatus::Acceptee));
+}
+
+#[test]
+fn test_commande_journal() {
+    let io = ScriptedIo::new(["o"]);
+    let mut game = partie_scriptee("rpg_engine_commande_journal", &io);
+    game.traiter_choix("journal").unwrap();
+    assert!(io.output_contains("Aucune quête en cours"));
+
+    game.player_mut().current_zone_id = 12;
+    game.traiter_choix("4").unwrap(); // Tom le Fermier
+    game.player_mut().add_monster_kill(5);
+    game.traiter_choix("quetes").unwrap();
+
+    assert!(io.output_contains("1. [en cours] Menace sur la Ferme"));
+    assert!(io.output_contains("👤 Donnée par Tom le Fermier (Ferme de Tom)"));
+    assert!(io.output_contains("⬜ Vaincre Bandit des Routes : 1/2"));
+    assert!(io.output_contains("⬜ Parler à Garde Royal : 0/1"));
+}
+
+#[test]
+fn test_proposition_declinee_puis_abandon() {
+    let io = ScriptedIo::new(["n"]);
+    let mut game = partie_scriptee("rpg_engine_abandon", &io);
+
+    // Gérard propose 'Herbes Rares' : on refuse, rien n'est noté
+    game.traiter_choix("3").unwrap();
+    assert!(io.output_contains("Acceptez-vous cette quête ? (o/n) : "));
+    assert!(io.output_contains("Reviens me voir si tu changes d'avis."));
+    assert_eq!(game.player().journal.status(1), None);
+
+    io.push_input("o");
+    game.traiter_choix("3").unwrap();
+    assert_eq!(game.player().journal.status(1), Some(QuestStatus::Acceptee));
+
+    // Les herbes trouvées retournent à Gérard quand on abandonne
+    let herbes = game.items().iter().find(|item| item.id == 5).unwrap().clone();
+    game.player_mut().inventaire.push(herbes);
+    io.push_input("o");
+    game.traiter_choix("abandon, 1").unwrap();
+    assert_eq!(game.player().journal.status(1), None);
+    assert_eq!(game.player().count_items(5), 0);
+    assert!(io.output_contains("Herbes Médicinales x1 rendu à Gérard le Marchand"));
+}
+
+#[test]
+fn test_arbre_de_dialogue() {
+    // [1] secrets, [1] je m'en charge : la quête démarre depuis le dialogue
+    let io = ScriptedIo::new(["1", "1"]);
+    let mut game = partie_scriptee("rpg_engine_dialogue", &io);
+    game.player_mut().current_zone_id = 11;
+    game.traiter_choix("3").unwrap();
+
+    assert!(io.output_contains("Elara la Sage : 'Bienvenue, jeune aventurier. Que puis-je pour toi ?'"));
+    assert!(!io.output_contains("accordez-moi votre bénédiction"));
+    assert_eq!(game.player().journal.status(2), Some(QuestStatus::Acceptee));
+    assert_eq!(io.remaining_inputs(), 0);
+
+    // Une fois la quête démarrée, « Je m'en charge » n'est plus proposé :
+    // [1] secrets, [1] revenir à l'accueil, [2] au revoir
+    for choix in ["1", "1", "2"] {
+        io.push_input(choix);
+    }
+    game.traiter_choix("3").unwrap();
+    assert_eq!(io.output().iter().filter(|line| line.contains("Je m'en charge")).count(), 1);
+    assert_eq!(io.remaining_inputs(), 0);
+}
+
+#[test]
+fn test_boutique_du_marchand() {
+    let io = ScriptedIo::default();
+    let mut game = partie_scriptee("rpg_engine_boutique", &io);
+    game.traiter_choix("boutique").unwrap();
+    assert!(io.output_contains("🛒 Boutique de Gérard le Marchand :"));
+    assert!(io.output_contains("[1] Potion de Soin - 25 pièces d'or (reste 5)"));
+
+    game.traiter_choix("acheter, 1").unwrap();
+    assert!(io.output_contains("Pas assez d'or"));
+    assert_eq!(game.player().count_items(1), 0);
+
+    game.player_mut().add_gold(30);
+    game.traiter_choix("acheter, 1").unwrap();
+    assert_eq!(game.player().gold, 5);
+    assert_eq!(game.player().count_items(1), 1);
+    assert_eq!(game.player().shops[&1].remaining(1), 4);
+
+    // Revente à moitié prix, jamais les objets de quête
+    game.traiter_choix("vendre, 1").unwrap();
+    assert_eq!(game.player().gold, 17);
+    let herbes = game.items().iter().find(|item| item.id == 5).unwrap().clone();
+    game.player_mut().inventaire.push(herbes);
+    game.traiter_choix("vendre, 1").unwrap();
+    assert!(io.output_contains("Gérard le Marchand refuse : Herbes Médicinales est un objet de quête."));
+    assert_eq!(game.player().count_items(5), 1);
+    assert_eq!(game.player().gold, 17);
+
+    // Le stock revient après assez d'actions
+    game.player_mut().actions += 30;
+    game.traiter_choix("boutique").unwrap();
+    assert!(io.output_contains("a reçu de la nouvelle marchandise"));
+    assert_eq!(game.player().shops[&1].remaining(1), 5);
+
+    // Pas de marchand dans la zone suivante
+    game.traiter_choix("1").unwrap();
+    game.traiter_choix("acheter, 1").unwrap();
+    assert!(io.output_contains("❌ Aucun marchand ici."));
+}
+
+#[test]
+fn test_monstre_vaincu_puis_reapparition() {
+    let io = ScriptedIo::new(std::iter::repeat_n("1", 20));
+    let mut game = partie_scriptee("rpg_engine_reapparition", &io);
+    game.player_mut().base_stats.strength = 200;
+    game.player_mut().current_zone_id = 12;
+
+    // Ferme de Tom : [1] Sud, [2] Ouest, [3] Nord, [4] Tom, [5] Bandit des Routes
+    game.traiter_choix("5").unwrap();
+    assert!(io.output_contains("Victoire ! Vous avez vaincu Bandit des Routes"));
+    assert!(!game.current_zone().unwrap().monsters[0].is_alive());
+    game.traiter_choix("5").unwrap();
+    assert!(io.output_contains("❌ Choix invalide."));
+
+    // L'état de la zone est sauvegardé avec le joueur
+    game.sauvegarder().unwrap();
+    let path = game.config().save_path();
+    let player = Player::load_character(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(player.zones[&12].is_defeated(&SpawnId { group: None, monster_id: 5, occurrence: 0 }));
+
+    let config = config_de_test("rpg_engine_reapparition");
+    let (data, _) = load_game_data(&config.data_dir, ValidationMode::Strict).unwrap();
+    let io = ScriptedIo::default();
+    let mut game = Game::from_parts(config, data, player, Box::new(io.clone())).unwrap();
+    assert!(!game.current_zone().unwrap().monsters[0].is_alive());
+
+    // Délai de la Ferme de Tom : 10 actions ; consulter ses stats ne fait pas avancer le monde
+    game.player_mut().actions += 9; // Vaincu à l'action 1 : de retour à l'action 11
+    game.traiter_choix("stat").unwrap();
+    assert_eq!(game.player().actions, 10);
+    assert!(!io.output_contains("rôde de nouveau"));
+    let potion = game.items().iter().find(|item| item.id == 1).unwrap().clone();
+    game.player_mut().inventaire.push(potion);
+    game.traiter_choix("inv, 1").unwrap();
+    assert!(io.output_contains("🔁 Bandit des Routes rôde de nouveau dans les parages !"));
+    assert!(game.current_zone().unwrap().monsters[0].is_alive());
+    assert!(game.player().zones.is_empty());
+}
+
+#[test]
+fn test_combat_contre_une_meute() {
+    // Toujours [1] : attaquer, puis viser le premier loup encore debout
+    let io = ScriptedIo::new(std::iter::repeat_n("1", 30));
+    let mut game = partie_scriptee("rpg_engine_meute", &io);
+    game.player_mut().base_stats.strength = 200;
+    game.player_mut().current_zone_id = 2;
+
+    // Forêt du Nord : [1] Sud, [2] Est, [3] Loup Sauvage, [4] Gobelin Voleur, [5] Meute de Loups
+    game.traiter_choix("5").unwrap();
+    assert!(io.output_contains("🥊 Vous engagez le combat contre 3 adversaires !"));
+    assert!(io.output_contains("🎯 Cibles :"));
+    assert!(io.output_contains("💀 Loup Sauvage est vaincu !"));
+    assert!(io.output_contains("🎉 Victoire ! Vous avez vaincu Loup Sauvage, Loup Sauvage, Loup Sauvage !"));
+
+    // Tant qu'ils sont debout, tous les loups attaquent à leur tour
+    let attaques = io.output().iter().filter(|line| line.contains("🔥 Loup Sauvage attaque !")).count();
+    assert_eq!(attaques, 9);
+
+    // Expérience et compteur cumulés sur toute la meute
+    assert!(io.output_contains("✨ Vous gagnez 45 points d'expérience !"));
+    let bilans = io.output().iter().filter(|line| line.contains("🏆 Vous avez maintenant tué 3 Loup Sauvage")).count();
+    assert_eq!(bilans, 1);
+    assert_eq!(game.player().get_monster_kills(1), 3);
+
+    // La meute a quitté la zone, le loup solitaire est toujours là
+    let zone = game.current_zone().unwrap();
+    assert!(zone.monsters[0].is_alive());
+    assert!(zone.monsters[2..].iter().all(|monster| !monster.is_alive()));
+    game.traiter_choix("5").unwrap();
+    assert!(io.output_contains("❌ Choix invalide."));
+}
+
+#[test]
+fn test_fuite_au_milieu_de_la_meute() {
+    // [1] attaquer le premier loup, puis [2] fuir les deux autres
+    let io = ScriptedIo::new(["1", "1", "2"]);
+    let mut game = partie_scriptee("rpg_engine_fuite_meute", &io);
+    game.player_mut().base_stats.strength = 200;
+    game.player_mut().current_zone_id = 2;
+
+    // Forêt du Nord : [5] Meute de Loups
+    game.traiter_choix("5").unwrap();
+    assert_eq!(io.remaining_inputs(), 0);
+    assert!(io.output_contains("💀 Loup Sauvage est vaincu !"));
+    assert!(io.output_contains("🏃 Vous fuyez le combat !"));
+    assert!(!io.output_contains("🎉 Victoire !"));
+
+    // Le loup abattu rapporte son expérience et compte pour les quêtes, malgré la fuite
+    assert!(io.output_contains("✨ Vous gagnez 15 points d'expérience !"));
+    assert!(io.output_contains("🏆 Vous avez maintenant tué 1 Loup Sauvage au total !"));
+    assert_eq!(game.player().get_monster_kills(1), 1);
+    assert_eq!(game.player().experience, 15);
+
+    // Seul le loup abattu a quitté la zone
+    let meute = &game.current_zone().unwrap().monsters[2..];
+    assert_eq!(meute.iter().filter(|monster| monster.is_alive()).count(), 2);
+}
+
+#[test]
+fn test_poison_achevant_le_dernier_monstre() {
+    // Aucune saisie : le loup, plus vif, succombe à son poison avant le tour du joueur
+    let io = ScriptedIo::default();
+    let config = config_de_test("rpg_engine_poison_fatal");
+    let (mut data, _) = load_game_data(&config.data_dir, ValidationMode::Strict).unwrap();
+    data.zones.get_mut(&2).unwrap().monsters[0].statuses.apply(StatusEffect {
+        kind: StatusKind::Poison,
+        duration: 3,
+        potency: 40,
+        modifiers: Attributes::default(),
+        stacks: 1,
+    });
+    let mut player = Player::new("Bot".to_string(), Attributes::new(100, 10, 5, 5));
+    player.rng = GameRng::new(GRAINE_DE_TEST);
+    let mut game = Game::from_parts(config, data, player, Box::new(io.clone())).unwrap();
+    game.player_mut().current_zone_id = 2;
+
+    // Forêt du Nord : [3] Loup Sauvage
+    game.traiter_choix("3").unwrap();
+    let succombe = io.output().iter().filter(|line| line.contains("☠️ Loup Sauvage succombe à ses blessures !")).count();
+    assert_eq!(succombe, 1);
+    assert!(!io.output_contains("🎯 Votre tour !"));
+    assert!(io.output_contains("🎉 Victoire ! Vous avez vaincu Loup Sauvage !"));
+    assert!(io.output_contains("✨ Vous gagnez 15 points d'expérience !"));
+    assert_eq!(game.player().get_monster_kills(1), 1);
+    assert!(!game.current_zone().unwrap().monsters[0].is_alive());
+}
+
+#[test]
+fn test_embuscade_a_l_arrivee() {
+    // Surpris, on ne peut agir qu'au second tour : [2] fuite
+    let io = ScriptedIo::new(["2"]);
+    let config = config_de_test("rpg_engine_embuscade");
+    let (mut data, _) = load_game_data(&config.data_dir, ValidationMode::Strict).unwrap();
+    data.zones.get_mut(&21).unwrap().ambush = 100;
+    // Plus lent que l'ours (4) et le squelette (8) : tous deux profitent de la surprise
+    let mut player = Player::new("Bot".to_string(), Attributes::new(100, 10, 5, 1));
+    player.rng = GameRng::new(GRAINE_DE_TEST);
+    let mut game = Game::from_parts(config, data, player, Box::new(io.clone())).unwrap();
+    game.player_mut().current_zone_id = 12;
+
+    // Ferme de Tom : [3] Nord vers la Clairière Mystique
+    game.traiter_choix("3").unwrap();
+    assert_eq!(game.player().current_zone_id, 21);
+    assert!(io.output_contains("⚠️ Embuscade !"));
+    assert!(io.output_contains("⚠️ Pris par surprise, vous ne pouvez pas réagir !"));
+    assert!(io.output_contains("🏃 Vous fuyez le combat !"));
+    assert_eq!(io.remaining_inputs(), 0);
+
+    // Le monstre surprend le joueur avant son premier tour
+    let sortie = io.output();
+    let surprise = sortie.iter().position(|line| line.contains("Pris par surprise")).unwrap();
+    let premier_tour = sortie.iter().position(|line| line.contains("🎯 Votre tour !")).unwrap();
+    assert!(sortie[surprise..premier_tour].iter().any(|line| line.contains("attaque !")));
+}
+
+#[test]
+fn test_potion_pendant_le_combat() {
+    // [3] objet, [2] épée non consommable puis annulation, [3] objet, [1] potion, puis fuite
+    let io = ScriptedIo::new(["3", "2", "3", "1", "2"]);
+    let mut game = partie_scriptee("rpg_engine_potion_combat", &io);
+    let potion = game.items().iter().find(|item| item.id == 1).unwrap().clone();
+    let epee = game.items().iter().find(|item| item.id == 2).unwrap().clone();
+    game.player_mut().inventaire.extend([potion, epee]);
+    game.player_mut().current_health = 40;
+    game.player_mut().current_zone_id = 2;
+    // Plus vif que le loup (12), sans le doubler : le joueur agit en premier, une fois par tour
+    game.player_mut().base_stats.agility = 20;
+
+    // Forêt du Nord : [1] Sud, [2] Est, [3] Loup Sauvage
+    game.traiter_choix("3").unwrap();
+    assert_eq!(io.remaining_inputs(), 0);
+    assert!(io.output_contains("❌ Numéro d'objet invalide !"));
+    assert!(io.output_contains("🍶 Vous utilisez Potion de Soin"));
+    assert_eq!(game.player().count_items(1), 0);
+
+    // Seule la potion a coûté un tour : le loup n'a attaqué qu'une fois
+    let attaques = io.output().iter().filter(|line| line.contains("🔥 Loup Sauvage attaque !")).count();
+    assert_eq!(attaques, 1);
+}
+
+#[test]
+fn test_competences_de_classe_en_combat() {
+    // [4] Soin Mineur, [4] Boule de Feu, [4] sans assez de mana, puis fuite
+    let io = ScriptedIo::new(["4", "2", "4", "1", "4", "1", "2"]);
+    let mut game = partie_scriptee("rpg_engine_competences", &io);
+    let player = game.player_mut();
+    player.classe = "Mage".to_string();
+    player.base_stats.mana = 30;
+    player.current_mana = 30;
+    player.current_health = 40;
+    player.current_zone_id = 2;
+
+    // Forêt du Nord : [1] Sud, [2] Est, [3] Loup Sauvage
+    game.traiter_choix("3").unwrap();
+    assert_eq!(io.remaining_inputs(), 0);
+    assert!(io.output_contains("✨ Vous utilisez Soin Mineur !"));
+    assert!(io.output_contains("✨ Vous utilisez Boule de Feu !"));
+    assert!(!io.output_contains("Pluie de Flammes")); // Débloquée au niveau 3
+    // 30 - 12 + 3 (recharge de fin de tour) - 15 + 3 = 9 : la troisième tentative échoue
+    assert!(io.output_contains("❌ Pas assez de mana pour Boule de Feu (15 requis, vous avez 9)."));
+    assert_eq!(game.player().current_mana, 9);
+}
+
+#[test]
+fn test_alterations_en_combat_et_hors_combat() {
+    // [3] objet, [1] Tonique du Guerrier, puis fuite au tour suivant
+    let io = ScriptedIo::new(["3", "1", "2"]);
+    let mut game = partie_scriptee("rpg_engine_alterations", &io);
+    let tonique = game.items().iter().find(|item| item.id == 12).unwrap().clone();
+    let player = game.player_mut();
+    player.inventaire = vec![tonique];
+    player.current_health = 40;
+    player.current_zone_id = 2;
+    player.statuses.apply(StatusEffect {
+        kind: StatusKind::Poison,
+        duration: 3,
+        potency: 5,
+        modifiers: Attributes::default(),
+        stacks: 1,
+    });
+
+    // Forêt du Nord : [1] Sud, [2] Est, [3] Loup Sauvage
+    game.traiter_choix("3").unwrap();
+    assert_eq!(io.remaining_inputs(), 0);
+    assert!(io.output_contains("🧑‍⚔️ Bot : 40/100 HP ☠️ Poison 5 (3 tours)"));
+    assert!(io.output_contains("✴️ Vous êtes affecté : 💪 Renforcement (+4 force) (6 tours)"));
+    // Deux tours de combat puis la commande elle-même : le poison s'épuise
+    let morsures = io.output().iter().filter(|line| line.contains("Vos altérations vous infligent 5 dégâts")).count();
+    assert_eq!(morsures, 3);
+    assert!(io.output_contains("☠️ Poison se dissipe."));
+
+    let player = game.player();
+    assert_eq!(player.statuses.get(StatusKind::Renforcement).unwrap().duration, 4);
+    assert_eq!(player.get_combat_stats().strength, player.get_total_stats().strength + 4);
+
+    // Hors combat, un poison ne peut pas achever le joueur
+    let player = game.player_mut();
+    player.current_health = 3;
+    player.statuses.apply(StatusEffect {
+        kind: StatusKind::Poison,
+        duration: 5,
+        potency: 10,
+        modifiers: Attributes::default(),
+        stacks: 1,
+    });
+    // Ni consulter ses stats ni se tromper de commande ne font passer un tour
+    let actions = game.player().actions;
+    game.traiter_choix("stat").unwrap();
+    game.traiter_choix("xyz").unwrap();
+    assert!(io.output_contains("✴️ Altérations: 💪 Renforcement (+4 force) (4 tours), ☠️ Poison 10 (5 tours)"));
+    assert_eq!(game.player().current_health, 3);
+    assert_eq!(game.player().actions, actions);
+
+    // Forêt du Nord : [1] Sud
+    game.traiter_choix("1").unwrap();
+    assert_eq!(game.player().current_health, 1);
+    assert!(game.player().is_alive());
+    assert_eq!(game.player().actions, actions + 1);
+}